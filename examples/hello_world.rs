@@ -10,7 +10,7 @@ fn App(cx: &Arc<Context>) -> View {
     rsx! {
         // redraw is important because the effects won't be applied
         impl size_auto, center, redraw
-        FlexRow {
+        FlexColumn {
             impl size_auto, redraw
             Card { content: "One".to_string() }
             impl size_auto, redraw
@@ -22,10 +22,9 @@ fn App(cx: &Arc<Context>) -> View {
 
 #[component]
 fn Card(cx: &Arc<Context>, content: String) -> View {
-    let bar = "-".repeat(content.len());
-
     rsx! {
-        "{bar}\n{content}\n{bar}"
+        impl size_auto, border(Border::new().border_type(BorderType::Rounded))
+        "{content}"
     }
     .view(&cx)
 }
@@ -3,7 +3,7 @@
 //! Converts parsed RSX AST into Rust code that constructs RSX objects.
 
 use crate::parse::*;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 
 /// Emits code for the root RSX
@@ -37,12 +37,15 @@ fn emit_deps(deps: &[Dep]) -> TokenStream {
 }
 
 /// Emits a Vec of dependencies as HookDependency trait objects
+///
+/// Clones the dependencies, so it must come before the scope closure
+/// takes them.
 fn emit_deps_vec(deps: &[Dep]) -> TokenStream {
     let deps = deps.iter().filter(|d| d.is_dep).map(|d| {
         let ident = &d.ident;
 
         quote! {
-            std::sync::Arc::new(#ident) as std::sync::Arc<dyn HookDependency>
+            std::sync::Arc::new(#ident.clone()) as std::sync::Arc<dyn HookDependency>
         }
     });
 
@@ -91,25 +94,87 @@ fn emit_node_scope(node: &RsxNode) -> TokenStream {
 
         RsxNode::If {
             deps,
-            cond,
+            branches,
+            otherwise,
+        } => {
+            let deps_emit = emit_deps(deps);
+            let deps_vec_emit = emit_deps_vec(deps);
+            // Hidden from the rsx, which may use the same name
+            let dependencies = Ident::new("dependencies", Span::mixed_site());
+            let branches = branches.iter().enumerate().map(|(i, (cond, children))| {
+                let kids = children.iter().map(emit_node);
+                quote! {
+                    if #cond {
+                        scope.branch(#i, |scope| { #(#kids)* });
+                    } else
+                }
+            });
+            // Without an `else`, the last branch has no children
+            let otherwise = otherwise.iter().flatten().map(emit_node);
+
+            quote! {
+                {
+                    #deps_emit
+                    let #dependencies = #deps_vec_emit;
+                    r.dynamic_scope(move |scope| {
+                        #(#branches)* {
+                            scope.branch(usize::MAX, |scope| { #(#otherwise)* });
+                        }
+                    }, #dependencies);
+                }
+            }
+        }
+
+        RsxNode::Match { deps, expr, arms } => {
+            let deps_emit = emit_deps(deps);
+            let deps_vec_emit = emit_deps_vec(deps);
+            // Hidden from the rsx, which may use the same name
+            let dependencies = Ident::new("dependencies", Span::mixed_site());
+            let arms = arms.iter().enumerate().map(|(i, arm)| {
+                let pat = &arm.pat;
+                let guard = arm.guard.as_ref().map(|guard| quote! { if #guard });
+                let kids = arm.children.iter().map(emit_node);
+                quote! {
+                    #pat #guard => scope.branch(#i, |scope| { #(#kids)* }),
+                }
+            });
+
+            quote! {
+                {
+                    #deps_emit
+                    let #dependencies = #deps_vec_emit;
+                    r.dynamic_scope(move |scope| {
+                        match #expr {
+                            #(#arms)*
+                        }
+                    }, #dependencies);
+                }
+            }
+        }
+
+        RsxNode::For {
+            deps,
+            pat,
+            expr,
+            key: None,
             children,
         } => {
             let deps_emit = emit_deps(deps);
             let deps_vec_emit = emit_deps_vec(deps);
+            // Hidden from the rsx, which may use the same name
+            let dependencies = Ident::new("dependencies", Span::mixed_site());
             let kids = children.iter().map(emit_node);
 
             quote! {
                 {
                     #deps_emit
+                    let #dependencies = #deps_vec_emit;
+                    #[allow(unused_parens)]
                     r.dynamic_scope(move |scope| {
-                        if #cond {
-                            if scope.children.lock().unwrap().is_empty() {
-                                #(#kids)*
-                            }
-                        } else {
-                            scope.children.lock().unwrap().clear();
+                        for #pat in #expr {
+                            #(#kids)*
                         }
-                    }, #deps_vec_emit);
+                    }, #dependencies);
                 }
             }
         }
@@ -118,22 +183,30 @@ fn emit_node_scope(node: &RsxNode) -> TokenStream {
             deps,
             pat,
             expr,
+            key: Some(key),
             children,
         } => {
             let deps_emit = emit_deps(deps);
             let deps_vec_emit = emit_deps_vec(deps);
+            // Hidden from the rsx, which may use the same name
+            let dependencies = Ident::new("dependencies", Span::mixed_site());
             let kids = children.iter().map(emit_node);
+            // Hidden from the loop body, which may use the same name
+            let previous = Ident::new("previous", Span::mixed_site());
 
             quote! {
                 {
                     #deps_emit
+                    let #dependencies = #deps_vec_emit;
                     #[allow(unused_parens)]
                     r.dynamic_scope(move |scope| {
-                        scope.children.lock().unwrap().clear();
+                        let mut #previous = scope.take_keyed();
                         for #pat in #expr {
-                            #(#kids)*
+                            scope.keyed(&mut #previous, osui::component::scope::key_of(&(#key)), |scope| {
+                                #(#kids)*
+                            });
                         }
-                    }, #deps_vec_emit);
+                    }, #dependencies);
                 }
             }
         }
@@ -180,6 +253,7 @@ fn emit_node(node: &RsxNode) -> TokenStream {
             plugins,
             path,
             props,
+            rest,
             children,
         } => {
             let prop_inits = props.iter().map(|p| {
@@ -189,18 +263,21 @@ fn emit_node(node: &RsxNode) -> TokenStream {
             });
 
             let emit_children = emit_rsx_vec(children);
+            let rest = rest.as_ref().map(|rest| quote! { ..#rest });
 
             let component_expr = if children.len() > 0 {
                 quote! {
                     #path {
                         #(#prop_inits,)*
-                        children: #emit_children
+                        children: #emit_children,
+                        #rest
                     }
                 }
             } else {
                 quote! {
                     #path {
                         #(#prop_inits,)*
+                        #rest
                     }
                 }
             };
@@ -228,6 +305,8 @@ fn emit_node(node: &RsxNode) -> TokenStream {
 
         RsxNode::If { .. } => panic!("Invalid if statement"),
 
+        RsxNode::Match { .. } => panic!("Invalid match statement"),
+
         RsxNode::For { .. } => panic!("Invalid for loop"),
 
         RsxNode::Expr(expr) => quote! {
@@ -9,7 +9,7 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{FnArg, ItemFn, Pat, ReturnType, Type, parse_macro_input};
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, ReturnType, Type};
 
 mod emit;
 mod parse;
@@ -23,6 +23,26 @@ mod parse;
 ///     Component {
 ///         prop: value,
 ///     }
+///     // Remaining props can be filled from a base value
+///     OtherComponent {
+///         prop: value,
+///         ..Default::default()
+///     }
+///     // Children of a branch keep their state while it stays taken
+///     %mode if *mode.get() == Mode::Edit {
+///         Editor {}
+///     } else {
+///         Preview {}
+///     }
+///     %status match *status.get() {
+///         Status::Loading => { "Loading..." }
+///         Status::Failed(code) if code >= 500 => { "Server error {code}" }
+///         _ => { Content {} }
+///     }
+///     // Children of an item keep their state while its key is rendered
+///     %todos for todo in todos.get() key todo.id {
+///         Todo { todo: todo.clone() }
+///     }
 /// }
 /// ```
 #[proc_macro]
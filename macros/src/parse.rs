@@ -7,12 +7,16 @@ use std::collections::HashSet;
 use proc_macro2::Span;
 use syn::parse::discouraged::Speculative;
 use syn::punctuated::Punctuated;
+use syn::{braced, parenthesized};
 use syn::{
-    Expr, Ident, LitStr, Pat, Path, Result, Token,
     parse::{Parse, ParseStream},
     token::Brace,
+    Expr, Ident, LitStr, Pat, Path, Result, Token,
 };
-use syn::{braced, parenthesized};
+
+mod kw {
+    syn::custom_keyword!(key);
+}
 
 /// Root of an RSX expression
 pub struct RsxRoot {
@@ -48,7 +52,7 @@ pub enum RsxNode {
     },
     /// Expression node: `{expr}`
     Expr(Expr),
-    /// Component instantiation: `Component { prop: value, ... }`
+    /// Component instantiation: `Component { prop: value, ..base, ... }`
     Component {
         /// View plugins
         plugins: Vec<ViewPlugin>,
@@ -56,33 +60,56 @@ pub enum RsxNode {
         path: Path,
         /// Component properties
         props: Vec<RsxProp>,
+        /// Base expression for the remaining properties: `..Default::default()`
+        rest: Option<Expr>,
         /// Child nodes
         children: Vec<RsxNode>,
     },
     /// Mount lifecycle: `@mount`
     Mount(Ident),
-    /// Conditional rendering: `@if condition { ... }`
+    /// Conditional rendering: `@if condition { ... } else if condition { ... } else { ... }`
     If {
         /// Dependencies to track for reactivity
         deps: Vec<Dep>,
-        /// Condition expression
-        cond: Expr,
-        /// Child nodes to render if true
-        children: Vec<RsxNode>,
+        /// Condition expression and child nodes of every `if` branch
+        branches: Vec<(Expr, Vec<RsxNode>)>,
+        /// Child nodes to render if no condition is true
+        otherwise: Option<Vec<RsxNode>>,
+    },
+    /// Pattern matching: `@match expr { pattern => { ... } }`
+    Match {
+        /// Dependencies to track for reactivity
+        deps: Vec<Dep>,
+        /// Matched expression
+        expr: Box<Expr>,
+        /// Match arms
+        arms: Vec<RsxArm>,
     },
     /// Loop rendering: `@for pattern in expr { ... }`
     For {
         /// Dependencies to track for reactivity
         deps: Vec<Dep>,
         /// Loop pattern (e.g., `(key, value)`)
-        pat: Pat,
+        pat: Box<Pat>,
         /// Iterable expression
-        expr: Expr,
+        expr: Box<Expr>,
+        /// Key identifying each item across renders: `key expr`
+        key: Option<Box<Expr>>,
         /// Child nodes to render for each iteration
         children: Vec<RsxNode>,
     },
 }
 
+/// Arm of a match node: `pattern if guard => { ... }`
+pub struct RsxArm {
+    /// Pattern matched against the expression
+    pub pat: Pat,
+    /// Optional guard condition
+    pub guard: Option<Expr>,
+    /// Child nodes to render if the arm is taken
+    pub children: Vec<RsxNode>,
+}
+
 pub struct Dep {
     pub ident: Ident,
     pub pat: Option<Pat>,
@@ -185,26 +212,83 @@ impl Parse for RsxNode {
 
         let deps = parse_deps(input)?;
 
-        // %$dep if $expr { $rsx }
+        // %$dep if $expr { $rsx } (else if $expr { $rsx })* (else { $rsx })?
         if input.peek(Token![if]) {
-            input.parse::<Token![if]>()?;
-            let cond: Expr = input.parse()?;
-            let content;
-            braced!(content in input);
-            let children = parse_children(&content)?;
+            let mut branches = Vec::new();
+            let mut otherwise = None;
+
+            loop {
+                input.parse::<Token![if]>()?;
+                let cond = Expr::parse_without_eager_brace(input)?;
+                let content;
+                braced!(content in input);
+                branches.push((cond, parse_children(&content)?));
+
+                if !input.peek(Token![else]) {
+                    break;
+                }
+                input.parse::<Token![else]>()?;
+
+                if !input.peek(Token![if]) {
+                    let content;
+                    braced!(content in input);
+                    otherwise = Some(parse_children(&content)?);
+                    break;
+                }
+            }
+
             return Ok(RsxNode::If {
                 deps,
-                cond,
-                children,
+                branches,
+                otherwise,
             });
         }
 
-        // %$dep for $pat in $expr { $rsx }
+        // %$dep match $expr { ($pat (if $expr)? => { $rsx } ,?)* }
+        if input.peek(Token![match]) {
+            input.parse::<Token![match]>()?;
+            let expr = Box::new(Expr::parse_without_eager_brace(input)?);
+            let content;
+            braced!(content in input);
+
+            let mut arms = Vec::new();
+            while !content.is_empty() {
+                let pat = Pat::parse_multi(&content)?;
+                let guard = if content.peek(Token![if]) {
+                    content.parse::<Token![if]>()?;
+                    Some(content.parse()?)
+                } else {
+                    None
+                };
+                content.parse::<Token![=>]>()?;
+                let body;
+                braced!(body in content);
+                arms.push(RsxArm {
+                    pat,
+                    guard,
+                    children: parse_children(&body)?,
+                });
+
+                if content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+
+            return Ok(RsxNode::Match { deps, expr, arms });
+        }
+
+        // %$dep for $pat in $expr (key $expr)? { $rsx }
         if input.peek(Token![for]) {
             input.parse::<Token![for]>()?;
-            let pat: Pat = Pat::parse_multi(input)?;
+            let pat = Box::new(Pat::parse_multi(input)?);
             input.parse::<Token![in]>()?;
-            let expr: Expr = input.parse()?;
+            let expr = Box::new(Expr::parse_without_eager_brace(input)?);
+            let key = if input.peek(kw::key) {
+                input.parse::<kw::key>()?;
+                Some(Box::new(Expr::parse_without_eager_brace(input)?))
+            } else {
+                None
+            };
             let content;
             braced!(content in input);
             let children = parse_children(&content)?;
@@ -212,6 +296,7 @@ impl Parse for RsxNode {
                 deps,
                 pat,
                 expr,
+                key,
                 children,
             });
         }
@@ -255,6 +340,7 @@ fn parse_component_invocation(input: ParseStream, plugins: Vec<ViewPlugin>) -> R
             plugins,
             path,
             props: Vec::new(),
+            rest: None,
             children: Vec::new(),
         });
     }
@@ -262,18 +348,33 @@ fn parse_component_invocation(input: ParseStream, plugins: Vec<ViewPlugin>) -> R
     let content;
     braced!(content in input);
 
-    let (props, children) = parse_props_and_children(&content)?;
+    let props = parse_props(&content)?;
+    let rest = parse_rest(&content)?;
+    let children = RsxRoot::parse(&content)?.nodes;
 
     Ok(RsxNode::Component {
         plugins,
         path,
         props,
+        rest,
         children,
     })
 }
 
-fn parse_props_and_children(input: ParseStream) -> Result<(Vec<RsxProp>, Vec<RsxNode>)> {
-    Ok((parse_props(input)?, RsxRoot::parse(input)?.nodes))
+/// Parses the optional `..base` that follows the props
+fn parse_rest(input: ParseStream) -> Result<Option<Expr>> {
+    if !input.peek(Token![..]) {
+        return Ok(None);
+    }
+
+    input.parse::<Token![..]>()?;
+    let rest: Expr = input.parse()?;
+
+    if input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+    }
+
+    Ok(Some(rest))
 }
 
 fn parse_props(input: ParseStream) -> Result<Vec<RsxProp>> {
@@ -0,0 +1,273 @@
+//! # Border Module
+//!
+//! Provides borders drawn around an area, with an optional title.
+//! Shared by the `Block` component and the `border` view plugin.
+
+use crate::{
+    layout::Padding,
+    render::{Alignment, Area, DrawContext, Point, Size, Style},
+    text::{display_width, truncate},
+};
+
+/// Set of line characters a border is drawn with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BorderType {
+    /// `┌─┐` single lines
+    #[default]
+    Plain,
+    /// `╭─╮` single lines with rounded corners
+    Rounded,
+    /// `╔═╗` double lines
+    Double,
+    /// `┏━┓` heavy lines
+    Thick,
+    /// `+-+` ASCII characters only
+    Ascii,
+}
+
+/// Characters used to draw each part of a border
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSet {
+    /// Top-left corner
+    pub top_left: char,
+    /// Top-right corner
+    pub top_right: char,
+    /// Bottom-left corner
+    pub bottom_left: char,
+    /// Bottom-right corner
+    pub bottom_right: char,
+    /// Top and bottom sides
+    pub horizontal: char,
+    /// Left and right sides
+    pub vertical: char,
+}
+
+/// Which sides of a border are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Borders {
+    /// Top side
+    pub top: bool,
+    /// Right side
+    pub right: bool,
+    /// Bottom side
+    pub bottom: bool,
+    /// Left side
+    pub left: bool,
+}
+
+/// Border drawn around a view, with an optional title
+///
+/// Used by the `Block` component and the `border` view plugin.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Border {
+    /// Characters the border is drawn with
+    pub border_type: BorderType,
+    /// Sides that are drawn
+    pub borders: Borders,
+    /// Text shown in the top border
+    pub title: Option<String>,
+    /// Position of the title in the top border
+    pub title_alignment: Alignment,
+    /// Space between the border and the content
+    pub padding: Padding,
+    /// Style of the border and title
+    pub style: Style,
+}
+
+impl BorderType {
+    /// Gets the characters of this border type
+    pub fn set(&self) -> BorderSet {
+        let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] = match self {
+            BorderType::Plain => ['┌', '┐', '└', '┘', '─', '│'],
+            BorderType::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            BorderType::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+            BorderType::Thick => ['┏', '┓', '┗', '┛', '━', '┃'],
+            BorderType::Ascii => ['+', '+', '+', '+', '-', '|'],
+        };
+
+        BorderSet {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+impl Borders {
+    /// Every side
+    pub const ALL: Borders = Borders {
+        top: true,
+        right: true,
+        bottom: true,
+        left: true,
+    };
+
+    /// No side
+    pub const NONE: Borders = Borders {
+        top: false,
+        right: false,
+        bottom: false,
+        left: false,
+    };
+}
+
+impl Default for Borders {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl Border {
+    /// Creates a plain border on every side
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the characters the border is drawn with
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    /// Sets the sides that are drawn
+    pub fn borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Sets the text shown in the top border
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the position of the title in the top border
+    pub fn title_alignment(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the space between the border and the content
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the style of the border and title
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Whether the top row is taken up, by the border or the title
+    fn has_top(&self) -> bool {
+        self.borders.top || self.title.is_some()
+    }
+
+    /// Total columns and rows taken up by the border and padding
+    pub fn thickness(&self) -> Size {
+        let padding = &self.padding;
+
+        Size {
+            width: (self.borders.left as u16 + self.borders.right as u16)
+                .saturating_add(padding.left)
+                .saturating_add(padding.right),
+            height: (self.has_top() as u16 + self.borders.bottom as u16)
+                .saturating_add(padding.top)
+                .saturating_add(padding.bottom),
+        }
+    }
+
+    /// Draws the border around the whole area of `ctx`
+    ///
+    /// Returns the area left inside the border and padding.
+    pub fn draw(&self, ctx: &mut DrawContext) -> Area {
+        let Area { width, height, .. } = ctx.area;
+        let set = self.border_type.set();
+        let borders = &self.borders;
+
+        if width == 0 || height == 0 {
+            return ctx.area.clone();
+        }
+
+        let line = |left: Option<char>, right: Option<char>| -> String {
+            (0..width)
+                .map(|x| match (x, left, right) {
+                    (0, Some(c), _) => c,
+                    (x, _, Some(c)) if x == width - 1 => c,
+                    _ => set.horizontal,
+                })
+                .collect()
+        };
+
+        if self.has_top() {
+            let text = match borders.top {
+                true => line(
+                    borders.left.then_some(set.top_left),
+                    borders.right.then_some(set.top_right),
+                ),
+                false => " ".repeat(width as usize),
+            };
+            ctx.draw_styled_text(Point { x: 0, y: 0 }, &text, self.style);
+        }
+
+        if borders.bottom && (height > 1 || !self.has_top()) {
+            let text = line(
+                borders.left.then_some(set.bottom_left),
+                borders.right.then_some(set.bottom_right),
+            );
+            ctx.draw_styled_text(
+                Point {
+                    x: 0,
+                    y: height - 1,
+                },
+                &text,
+                self.style,
+            );
+        }
+
+        let first = self.has_top() as u16;
+        let last = height - (borders.bottom as u16).min(height - first);
+        let vertical = set.vertical.to_string();
+        for y in first..last {
+            if borders.left {
+                ctx.draw_styled_text(Point { x: 0, y }, &vertical, self.style);
+            }
+            if borders.right && width > 1 {
+                ctx.draw_styled_text(Point { x: width - 1, y }, &vertical, self.style);
+            }
+        }
+
+        if let Some(title) = &self.title {
+            let start = borders.left as u16;
+            let space = width.saturating_sub(start + borders.right as u16);
+            let title = truncate(title, space);
+            let free = space - display_width(title);
+            let offset = match self.title_alignment {
+                Alignment::Left | Alignment::Justify => 0,
+                Alignment::Center => free / 2,
+                Alignment::Right => free,
+            };
+            ctx.draw_styled_text(
+                Point {
+                    x: start + offset,
+                    y: 0,
+                },
+                title,
+                self.style,
+            );
+        }
+
+        let inner = Area {
+            x: ctx.area.x.saturating_add((borders.left as u16).min(width)),
+            y: ctx.area.y.saturating_add(first.min(height)),
+            width: width.saturating_sub(borders.left as u16 + borders.right as u16),
+            height: height.saturating_sub(first + borders.bottom as u16),
+        };
+
+        self.padding.inner(&inner)
+    }
+}
@@ -0,0 +1,250 @@
+//! # Buffer Module
+//!
+//! Provides a grid of cells that drawing instructions are rasterized into.
+//! Engines keep the previous frame's buffer around so only the cells that
+//! changed between two frames need to be written to the terminal.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::render::{Area, DrawContext, DrawInstruction, Point, Style};
+use crate::text::{grapheme_width, layout_paragraph};
+
+/// A single terminal cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    /// The grapheme displayed in this cell, empty when the cell is
+    /// covered by the wide character to its left
+    pub symbol: String,
+    /// The style the symbol is displayed with
+    pub style: Style,
+}
+
+/// A rectangular grid of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Buffer {
+    /// Width in terminal columns
+    pub width: u16,
+    /// Height in terminal rows
+    pub height: u16,
+    /// Cells in row-major order
+    cells: Vec<Cell>,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: " ".to_string(),
+            style: Style::default(),
+        }
+    }
+}
+
+impl Buffer {
+    /// Creates a blank buffer of the given size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::default(); width as usize * height as usize],
+        }
+    }
+
+    /// Resizes the buffer, discarding its contents
+    pub fn resize(&mut self, width: u16, height: u16) {
+        *self = Self::new(width, height);
+    }
+
+    /// Resets every cell to blank
+    pub fn reset(&mut self) {
+        self.cells.fill(Cell::default());
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y as usize * self.width as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the cell at the given position, if it is inside the buffer
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    /// Gets the cell at the given position mutably, if it is inside the buffer
+    pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        self.index(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// Writes a single line of styled text starting at the given position
+    ///
+    /// Text is written grapheme by grapheme. A wide character takes up
+    /// two cells, the second one holding an empty symbol. Characters
+    /// falling outside the buffer are discarded, and a wide character
+    /// crossing the right edge is replaced with blank cells.
+    pub fn set_str(&mut self, x: u16, y: u16, text: &str, style: Style) {
+        self.set_str_clipped(x as u32, y, text, style, 0, self.width);
+    }
+
+    /// Like `set_str`, but only writes the columns from `left` up to `right`
+    ///
+    /// Wide characters crossing either edge are replaced with blank cells.
+    fn set_str_clipped(&mut self, x: u32, y: u16, text: &str, style: Style, left: u16, right: u16) {
+        let (left, right) = (left as u32, right.min(self.width) as u32);
+        let mut x = x;
+
+        for grapheme in text.graphemes(true) {
+            let width = grapheme_width(grapheme) as u32;
+            let end = x + width;
+
+            if width == 0 || end <= left {
+                x = end;
+                continue;
+            }
+            if x >= right {
+                break;
+            }
+
+            if x < left || end > right {
+                for column in x.max(left)..end.min(right) {
+                    self.put(column as u16, y, " ", style);
+                }
+            } else {
+                self.put(x as u16, y, grapheme, style);
+                for column in x + 1..end {
+                    self.put(column as u16, y, "", style);
+                }
+            }
+            x = end;
+        }
+    }
+
+    /// Sets a single cell, blanking what is left of a wide character it overwrites
+    fn put(&mut self, x: u16, y: u16, symbol: &str, style: Style) {
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+
+        // Overwriting the start of a wide character leaves its other half behind
+        for column in x + 1..x.saturating_add(grapheme_width(&self.cells[i].symbol)) {
+            if let Some(cell) = self.get_mut(column, y).filter(|c| c.symbol.is_empty()) {
+                cell.symbol = " ".to_string();
+            }
+        }
+
+        // Overwriting the second half of a wide character leaves its start behind
+        if self.cells[i].symbol.is_empty() && !symbol.is_empty() {
+            for column in (0..x).rev() {
+                let cell = &mut self.cells[i - (x - column) as usize];
+                let continuation = cell.symbol.is_empty();
+                cell.symbol = " ".to_string();
+                if !continuation {
+                    break;
+                }
+            }
+        }
+
+        let cell = &mut self.cells[i];
+        cell.symbol = symbol.to_string();
+        cell.style = style;
+    }
+
+    /// Rasterizes the drawing instructions of a draw context into the buffer
+    ///
+    /// `View` instructions are rendered into a fresh `DrawContext` first,
+    /// the same way `Engine::render_view` does. Every instruction is
+    /// clipped to the area of the context it belongs to, and nested
+    /// contexts are clipped to their parents as well.
+    pub fn draw_context(&mut self, ctx: &DrawContext) {
+        self.draw_clipped(ctx, &ctx.area);
+    }
+
+    fn draw_clipped(&mut self, ctx: &DrawContext, clip: &Area) {
+        for inst in &ctx.drawing {
+            match inst {
+                DrawInstruction::Text(point, text) => {
+                    self.draw_text(ctx, clip, point, text, Style::default())
+                }
+                DrawInstruction::StyledText(point, text, style) => {
+                    self.draw_text(ctx, clip, point, text, *style)
+                }
+                DrawInstruction::Paragraph(point, text, options) => {
+                    let width = ctx.area.width.saturating_sub(point.x);
+                    let height = ctx.area.height.saturating_sub(point.y);
+
+                    for (i, line) in layout_paragraph(text, options, width, height)
+                        .iter()
+                        .enumerate()
+                    {
+                        let point = Point {
+                            x: point.x,
+                            y: point.y.saturating_add(i as u16),
+                        };
+                        self.draw_text(ctx, clip, &point, line, options.style);
+                    }
+                }
+                DrawInstruction::Child(_point, child) => {
+                    self.draw_clipped(child, &child.area.intersection(clip))
+                }
+                DrawInstruction::View(area, view) => {
+                    let mut child = DrawContext::new(area.clone());
+                    view(&mut child);
+                    self.draw_clipped(&child, &area.intersection(clip));
+                }
+            }
+        }
+    }
+
+    fn draw_text(
+        &mut self,
+        ctx: &DrawContext,
+        clip: &Area,
+        point: &Point,
+        text: &str,
+        style: Style,
+    ) {
+        let x = ctx.area.x as u32 + point.x as u32;
+        let y = ctx.area.y as u32 + point.y as u32;
+
+        for (i, line) in text.lines().enumerate() {
+            let y = y + i as u32;
+            if y < clip.y as u32 {
+                continue;
+            }
+            if y >= clip.bottom() as u32 {
+                break;
+            }
+            self.set_str_clipped(x, y as u16, line, style, clip.x, clip.right());
+        }
+    }
+
+    /// Returns the cells of `next` that differ from this buffer
+    ///
+    /// Both buffers must have the same size; every cell of `next` is
+    /// returned otherwise.
+    pub fn diff<'a>(&self, next: &'a Buffer) -> Vec<(u16, u16, &'a Cell)> {
+        let same_size = self.width == next.width && self.height == next.height;
+
+        next.cells
+            .iter()
+            .enumerate()
+            .filter(|(i, cell)| !same_size || self.cells[*i] != **cell)
+            .map(|(i, cell)| {
+                (
+                    (i % next.width as usize) as u16,
+                    (i / next.width as usize) as u16,
+                    cell,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the symbols of the buffer as text, one string per row
+    pub fn to_lines(&self) -> Vec<String> {
+        self.cells
+            .chunks(self.width.max(1) as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol.as_str()).collect())
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::component_prelude::*;
+
+/// Bordered container laying out its children inside the border
+///
+/// The block takes up its whole area. Children are drawn in the area
+/// left inside the border and padding, like the children of a view
+/// without a layout.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     Block {
+///         border_type: BorderType::Rounded,
+///         title: Some("Settings".to_string()),
+///         title_alignment: Alignment::Center,
+///         padding: Padding::symmetric(0, 1),
+///         ..Default::default()
+///
+///         "Content"
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Block {
+    /// Characters the border is drawn with
+    pub border_type: BorderType,
+    /// Sides that are drawn
+    pub borders: Borders,
+    /// Text shown in the top border
+    pub title: Option<String>,
+    /// Position of the title in the top border
+    pub title_alignment: Alignment,
+    /// Space between the border and the children
+    pub padding: Padding,
+    /// Style of the border and title
+    pub style: Style,
+    /// The children drawn inside the border
+    pub children: Rsx,
+}
+
+impl ComponentImpl for Block {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        self.children.generate_children(cx);
+
+        let border = Border {
+            border_type: self.border_type,
+            borders: self.borders,
+            title: self.title.clone(),
+            title_alignment: self.title_alignment,
+            padding: self.padding,
+            style: self.style,
+        };
+        let cx = cx.clone();
+
+        Arc::new(move |ctx| {
+            let area = ctx.allocate(ctx.area.x, ctx.area.y, ctx.area.width, ctx.area.height);
+
+            let mut frame = DrawContext::new(area);
+            let inner = border.draw(&mut frame);
+
+            let mut content = DrawContext::new(inner.clone());
+            cx.draw_children(&mut content);
+            frame.draw(DrawInstruction::Child(
+                Point {
+                    x: inner.x - frame.area.x,
+                    y: inner.y - frame.area.y,
+                },
+                content,
+            ));
+
+            ctx.draw(DrawInstruction::Child(Point { x: 0, y: 0 }, frame));
+        })
+    }
+}
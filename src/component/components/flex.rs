@@ -1,51 +1,149 @@
 use crate::component_prelude::*;
 
+/// Flexbox container laying out its children along one axis
+///
+/// Every child is measured in the container's area first, then sized
+/// and positioned according to the container settings and the child's
+/// `FlexStyle`, set with the `flex` view plugin. Children never extend
+/// past the container's area.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     Flex {
+///         direction: Direction::Row,
+///         gap: 1,
+///         justify: Justify::SpaceBetween,
+///         ..Default::default()
+///
+///         impl size_auto, redraw
+///         "Left"
+///         impl size_auto, flex(FlexStyle::new().grow(1)), redraw
+///         "Middle"
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Flex {
+    /// Axis along which children are placed
+    pub direction: Direction,
+    /// Cells between adjacent children
+    pub gap: u16,
+    /// Space between the container edges and its children
+    pub padding: Padding,
+    /// Distribution of free space along the main axis
+    pub justify: Justify,
+    /// Placement of children along the cross axis
+    pub align: Align,
+    /// The children to lay out
+    pub children: Rsx,
+}
+
+impl ComponentImpl for Flex {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        self.children.generate_children(cx);
+
+        flex_view(
+            cx,
+            FlexLayout {
+                direction: self.direction,
+                gap: self.gap,
+                padding: self.padding,
+                justify: self.justify,
+                align: self.align,
+            },
+        )
+    }
+}
+
+/// Lays out its children left to right
+///
+/// Shorthand for a `Flex` with `Direction::Row` and default settings.
 #[component]
 pub fn FlexRow(cx: &Arc<Context>, children: Rsx) -> View {
-    children.generate_children(&cx);
+    children.generate_children(cx);
 
-    Arc::new({
-        let cx = cx.clone();
-        move |ctx| {
-            for (child, view_wrapper) in cx.get_children() {
-                let view = child.get_view();
+    flex_view(
+        cx,
+        FlexLayout {
+            direction: Direction::Row,
+            ..Default::default()
+        },
+    )
+}
 
-                if let Some(view_wrapper) = view_wrapper {
-                    let mut ctx2 = ctx.clone();
+/// Lays out its children top to bottom
+///
+/// Shorthand for a `Flex` with `Direction::Column` and default settings.
+#[component]
+pub fn FlexColumn(cx: &Arc<Context>, children: Rsx) -> View {
+    children.generate_children(cx);
 
-                    view_wrapper(&mut ctx2, view);
+    flex_view(
+        cx,
+        FlexLayout {
+            direction: Direction::Column,
+            ..Default::default()
+        },
+    )
+}
+
+/// Runs a child's view wrapper in `area`
+///
+/// Children without a wrapper are drawn over the whole area and
+/// measured with `size_auto`.
+pub(super) fn place(view: View, view_wrapper: &Option<ViewWrapper>, area: Area) -> DrawContext {
+    let mut ctx = DrawContext::new(area.clone());
 
-                    ctx.drawing.append(&mut ctx2.drawing);
-                    ctx.area.y += ctx2.allocated.height;
-                } else {
-                    ctx.draw_view(ctx.area.clone(), view);
-                }
-            }
+    match view_wrapper {
+        Some(view_wrapper) => view_wrapper(&mut ctx, view),
+        None => {
+            ctx.draw_view(area, view.clone());
+            size_auto(&mut ctx, &view);
         }
-    })
+    }
+
+    ctx
 }
 
-#[component]
-pub fn FlexColumn(cx: &Arc<Context>, children: Rsx) -> View {
-    children.generate_children(&cx);
+fn flex_view(cx: &Arc<Context>, layout: FlexLayout) -> View {
+    let cx = cx.clone();
 
-    Arc::new({
-        let cx = cx.clone();
-        move |ctx| {
-            for (child, view_wrapper) in cx.get_children() {
-                let view = child.get_view();
+    Arc::new(move |ctx| {
+        let children = cx.get_children();
+        let inner = layout.padding.inner(&ctx.area);
 
-                if let Some(view_wrapper) = view_wrapper {
-                    let mut ctx2 = ctx.clone();
+        // Measure every child in the space available to all of them
+        let measured: Vec<(Size, FlexStyle)> = children
+            .iter()
+            .map(|(child, view_wrapper)| {
+                let c = place(child.get_view(), view_wrapper, inner.clone());
+                let size = Size {
+                    width: c.allocated.width,
+                    height: c.allocated.height,
+                };
+                (size, c.flex.unwrap_or_default())
+            })
+            .collect();
 
-                    view_wrapper(&mut ctx2, view);
+        let areas = layout.arrange(&ctx.area, &measured);
 
-                    ctx.drawing.append(&mut ctx2.drawing);
-                    ctx.area.x += ctx2.allocated.width;
-                } else {
-                    ctx.draw_view(ctx.area.clone(), view);
-                }
-            }
+        for ((child, view_wrapper), area) in children.iter().zip(areas) {
+            let point = Point {
+                x: area.x - ctx.area.x,
+                y: area.y - ctx.area.y,
+            };
+            let c = place(child.get_view(), view_wrapper, area);
+            ctx.draw(DrawInstruction::Child(point, c));
         }
+
+        let size = layout.measure(&measured);
+        ctx.allocate(
+            ctx.area.x,
+            ctx.area.y,
+            size.width.min(ctx.area.width),
+            size.height.min(ctx.area.height),
+        );
     })
 }
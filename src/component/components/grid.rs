@@ -0,0 +1,110 @@
+use crate::component_prelude::*;
+
+use super::flex::place;
+
+/// Grid container placing its children in rows and columns
+///
+/// Tracks are sized from their `Track` template: fixed lengths first,
+/// then `Auto` tracks from the children spanning only them, then
+/// `MinMax` tracks up to their maximum, and the remaining space is
+/// shared between `Fr` tracks. Children choose their cells with the
+/// `grid_area`, `grid_cell` and `grid_span` view plugins and are placed
+/// in the first free cells otherwise.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     Grid {
+///         rows: vec![Track::Length(1), Track::Fr(1)],
+///         columns: vec![Track::minmax(10, Track::Fr(1)), Track::Fr(3)],
+///         areas: vec!["header header".to_string(), "sidebar main".to_string()],
+///         column_gap: 1,
+///         ..Default::default()
+///
+///         impl grid_area("header")
+///         "Dashboard"
+///         impl grid_area("sidebar")
+///         Menu {}
+///         impl grid_area("main")
+///         Content {}
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Grid {
+    /// Row tracks; rows beyond these are sized `Track::Auto`
+    pub rows: Vec<Track>,
+    /// Column tracks; a single `Track::Fr(1)` column when empty
+    pub columns: Vec<Track>,
+    /// Named areas, one string per row with space-separated names per column
+    pub areas: Vec<String>,
+    /// Cells between adjacent rows
+    pub row_gap: u16,
+    /// Cells between adjacent columns
+    pub column_gap: u16,
+    /// Space between the container edges and its children
+    pub padding: Padding,
+    /// The children to lay out
+    pub children: Rsx,
+}
+
+impl ComponentImpl for Grid {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        self.children.generate_children(cx);
+
+        let layout = GridLayout {
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+            areas: self.areas.clone(),
+            row_gap: self.row_gap,
+            column_gap: self.column_gap,
+            padding: self.padding,
+        };
+        let cx = cx.clone();
+
+        Arc::new(move |ctx| {
+            let children = cx.get_children();
+            let inner = layout.padding.inner(&ctx.area);
+
+            let measured: Vec<(Size, Option<GridPlacement>)> = children
+                .iter()
+                .map(|(child, view_wrapper)| {
+                    let c = place(child.get_view(), view_wrapper, inner.clone());
+                    let size = Size {
+                        width: c.allocated.width,
+                        height: c.allocated.height,
+                    };
+                    (size, c.grid)
+                })
+                .collect();
+
+            let areas = layout.arrange(&ctx.area, &measured);
+            let (mut right, mut bottom) = (ctx.area.x, ctx.area.y);
+
+            for ((child, view_wrapper), area) in children.iter().zip(areas) {
+                right = right.max(area.right());
+                bottom = bottom.max(area.bottom());
+
+                let point = Point {
+                    x: area.x - ctx.area.x,
+                    y: area.y - ctx.area.y,
+                };
+                let c = place(child.get_view(), view_wrapper, area);
+                ctx.draw(DrawInstruction::Child(point, c));
+            }
+
+            let padding = &layout.padding;
+            ctx.allocate(
+                ctx.area.x,
+                ctx.area.y,
+                (right - ctx.area.x)
+                    .saturating_add(padding.right)
+                    .min(ctx.area.width),
+                (bottom - ctx.area.y)
+                    .saturating_add(padding.bottom)
+                    .min(ctx.area.height),
+            );
+        })
+    }
+}
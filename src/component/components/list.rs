@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+
+use crate::component_prelude::*;
+
+/// Draws a single item into its row; the flag tells whether it is selected
+pub type ItemRenderer<T> = Arc<dyn Fn(&mut DrawContext, &T, bool) + Send + Sync>;
+
+/// Number of rows scrolled per mouse wheel step
+const WHEEL_STEP: usize = 3;
+
+/// Scrollable list of items with a selected index
+///
+/// Every item takes one row. Only the rows inside the area are drawn and
+/// no `Context` is created per item, so long lists stay cheap to render.
+/// The visible items are cloned each frame and drawn without holding the
+/// lock of `items`, so `render_item` may read the list.
+/// The list is focusable and reacts to keys while focused.
+///
+/// # Keys
+/// - `Up`/`Down`: move the selection
+/// - `PageUp`/`PageDown`: move the selection by a page
+/// - `Home`/`End`: select the first/last item
+///
+/// The mouse wheel scrolls the list and clicking a row selects it.
+pub struct List<T> {
+    /// The items to display
+    pub items: State<Vec<T>>,
+    /// Index of the selected item
+    pub selected: State<usize>,
+    /// Draws an item at `Point { x: 0, y: 0 }` of its row
+    pub render_item: ItemRenderer<T>,
+}
+
+impl<T: Display + Send + Sync + 'static> Default for List<T> {
+    /// Creates an empty list drawing items with `Display`,
+    /// highlighting the selected one
+    fn default() -> Self {
+        Self {
+            items: State::new(Vec::new()),
+            selected: State::new(0),
+            render_item: Arc::new(|ctx, item: &T, selected| {
+                let text = item.to_string();
+                if selected {
+                    ctx.draw_styled_text(Point { x: 0, y: 0 }, &text, Style::new().reverse());
+                } else {
+                    ctx.draw_text(Point { x: 0, y: 0 }, &text);
+                }
+            }),
+        }
+    }
+}
+
+/// Scroll state of a list
+#[derive(Debug, Clone)]
+struct Viewport {
+    /// Index of the first visible item
+    offset: usize,
+    /// Area the list was last drawn in
+    area: Area,
+    /// Selection at the last render, to scroll only when it changes
+    selected: Option<usize>,
+}
+
+impl<T: Clone + Send + Sync + 'static> ComponentImpl for List<T> {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        use_focus(cx);
+
+        let viewport = use_hook(|| {
+            Arc::new(Mutex::new(Viewport {
+                offset: 0,
+                area: Area {
+                    x: 0,
+                    y: 0,
+                    width: 0,
+                    height: 0,
+                },
+                selected: None,
+            }))
+        });
+
+        cx.on_event({
+            let items = self.items.clone();
+            let selected = self.selected.clone();
+            let viewport = viewport.clone();
+            move |cx, key: &KeyEvent| {
+                if !cx.is_focused() || key.kind == KeyEventKind::Release {
+                    return;
+                }
+
+                let len = items.get().len();
+                let page = (viewport.lock().unwrap().area.height as usize).max(1);
+                let current = selected.get_dl();
+
+                let next = match key.code {
+                    KeyCode::Up => current.saturating_sub(1),
+                    KeyCode::Down => current + 1,
+                    KeyCode::PageUp => current.saturating_sub(page),
+                    KeyCode::PageDown => current + page,
+                    KeyCode::Home => 0,
+                    KeyCode::End => len.saturating_sub(1),
+                    _ => return,
+                }
+                .min(len.saturating_sub(1));
+
+                if next != current {
+                    selected.set(next);
+                }
+            }
+        });
+
+        cx.on_event({
+            let items = self.items.clone();
+            let selected = self.selected.clone();
+            let viewport = viewport.clone();
+            move |cx, mouse: &MouseEvent| {
+                let mut viewport = viewport.lock().unwrap();
+                let area = &viewport.area;
+
+                if !area.contains(mouse.column, mouse.row) {
+                    return;
+                }
+
+                let len = items.get().len();
+                let max_offset = len.saturating_sub(area.height as usize);
+                let row = viewport.offset + (mouse.row - area.y) as usize;
+
+                match mouse.kind {
+                    MouseEventKind::ScrollUp => {
+                        viewport.offset = viewport.offset.saturating_sub(WHEEL_STEP);
+                        cx.request_redraw();
+                    }
+                    MouseEventKind::ScrollDown => {
+                        viewport.offset = (viewport.offset + WHEEL_STEP).min(max_offset);
+                        cx.request_redraw();
+                    }
+                    MouseEventKind::Down(MouseButton::Left) if row < len => {
+                        drop(viewport);
+                        selected.set(row);
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        let items = self.items.clone();
+        let selected = self.selected.clone();
+        let render_item = self.render_item.clone();
+
+        Arc::new(move |ctx| {
+            let height = ctx.area.height as usize;
+
+            let (visible, selected, offset) = {
+                let items = items.get();
+                let selected = selected.get_dl().min(items.len().saturating_sub(1));
+
+                let mut viewport = viewport.lock().unwrap();
+                viewport.area = ctx.area.clone();
+
+                // Follow the selection only when it moved, so the wheel can scroll freely
+                if viewport.selected != Some(selected) {
+                    if selected < viewport.offset {
+                        viewport.offset = selected;
+                    } else if height > 0 && selected >= viewport.offset + height {
+                        viewport.offset = selected + 1 - height;
+                    }
+                    viewport.selected = Some(selected);
+                }
+                viewport.offset = viewport.offset.min(items.len().saturating_sub(height));
+
+                let visible: Vec<T> = items
+                    .iter()
+                    .skip(viewport.offset)
+                    .take(height)
+                    .cloned()
+                    .collect();
+                (visible, selected, viewport.offset)
+            };
+
+            for (row, item) in visible.iter().enumerate() {
+                let mut row_ctx = DrawContext::new(Area {
+                    x: ctx.area.x,
+                    y: ctx.area.y.saturating_add(row as u16),
+                    width: ctx.area.width,
+                    height: 1,
+                });
+                render_item(&mut row_ctx, item, offset + row == selected);
+                ctx.draw(DrawInstruction::Child(
+                    Point {
+                        x: 0,
+                        y: row as u16,
+                    },
+                    row_ctx,
+                ));
+            }
+        })
+    }
+}
@@ -1,3 +1,17 @@
+mod block;
 mod flex;
+mod grid;
+mod list;
+mod paragraph;
+mod table;
+mod text_area;
+mod text_input;
 
+pub use block::*;
 pub use flex::*;
+pub use grid::*;
+pub use list::*;
+pub use paragraph::*;
+pub use table::*;
+pub use text_area::*;
+pub use text_input::*;
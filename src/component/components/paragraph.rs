@@ -0,0 +1,51 @@
+use crate::component_prelude::*;
+
+/// Block of text wrapped and aligned to its area
+///
+/// Text that does not fit vertically is cut off, optionally ending with
+/// `…`. Sized with the `*_auto` plugins, the paragraph reports its height
+/// wrapped to the available width.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     impl height_auto, redraw
+///     Paragraph {
+///         text: help.clone(),
+///         alignment: Alignment::Justify,
+///         ellipsis: true,
+///         ..Default::default()
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Paragraph {
+    /// The text to display, lines separated by `\n`
+    pub text: String,
+    /// How lines wider than the area are broken
+    pub wrap: Wrap,
+    /// Horizontal alignment of every line
+    pub alignment: Alignment,
+    /// Number of wrapped lines scrolled out of view at the top
+    pub scroll: u16,
+    /// Whether cut off text ends with `…`
+    pub ellipsis: bool,
+    /// Style of the text
+    pub style: Style,
+}
+
+impl ComponentImpl for Paragraph {
+    fn call(&self, _cx: &Arc<Context>) -> View {
+        let text = self.text.clone();
+        let options = ParagraphOptions {
+            wrap: self.wrap,
+            alignment: self.alignment,
+            scroll: self.scroll,
+            ellipsis: self.ellipsis,
+            style: self.style,
+        };
+
+        Arc::new(move |ctx| ctx.draw_paragraph(Point { x: 0, y: 0 }, &text, options.clone()))
+    }
+}
@@ -0,0 +1,462 @@
+use std::cmp::Ordering;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::component_prelude::*;
+
+/// Produces the text of a cell from a row
+pub type CellText<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Compares two rows for sorting
+pub type Comparator<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+/// How the width of a column is determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnWidth {
+    /// A fixed number of columns
+    Fixed(u16),
+    /// A percentage of the table width
+    Percent(u16),
+    /// The width of the widest cell, header included
+    Auto,
+}
+
+/// A column of a `Table`
+pub struct Column<T> {
+    /// Text shown in the header row
+    pub title: String,
+    /// How the width of the column is determined
+    pub width: ColumnWidth,
+    /// Alignment of the header and cells
+    pub align: Alignment,
+    /// Produces the text of a cell
+    pub cell: CellText<T>,
+    /// Comparator used when sorting by this column
+    pub compare: Option<Comparator<T>>,
+}
+
+/// The column a table is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortOrder {
+    /// Index of the column
+    pub column: usize,
+    /// Whether the order is reversed
+    pub descending: bool,
+}
+
+/// Table of rows with a header, column sizing, selection and sorting
+///
+/// The header row stays in place while the rows scroll, and only the
+/// visible rows are drawn. Cells wider than their column are truncated
+/// with an ellipsis. The table is focusable and reacts to keys while
+/// focused.
+///
+/// # Keys
+/// - `Up`/`Down`, `PageUp`/`PageDown`, `Home`/`End`: move the selection
+/// - `1`-`9`: sort by that column, pressing again reverses the order
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     Table {
+///         columns: vec![
+///             Column::new("Name", |p: &Person| p.name.clone())
+///                 .sort_by(|a, b| a.name.cmp(&b.name)),
+///             Column::new("Age", |p: &Person| p.age.to_string())
+///                 .width(ColumnWidth::Fixed(5))
+///                 .align(Alignment::Right),
+///         ],
+///         rows: people.clone(),
+///         ..Default::default()
+///     }
+/// }
+/// ```
+pub struct Table<T> {
+    /// The columns to display
+    pub columns: Vec<Column<T>>,
+    /// The rows to display
+    pub rows: State<Vec<T>>,
+    /// Index of the selected row, in display order
+    pub selected: State<usize>,
+    /// The current sort order, if any
+    pub sort: State<Option<SortOrder>>,
+}
+
+impl<T: Send + Sync + 'static> Default for Table<T> {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: State::new(Vec::new()),
+            selected: State::new(0),
+            sort: State::new(None),
+        }
+    }
+}
+
+impl<T> Column<T> {
+    /// Creates an auto-sized, left-aligned column
+    pub fn new<F: Fn(&T) -> String + Send + Sync + 'static>(title: &str, cell: F) -> Self {
+        Self {
+            title: title.to_string(),
+            width: ColumnWidth::Auto,
+            align: Alignment::Left,
+            cell: Arc::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Sets how the width of the column is determined
+    pub fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the alignment of the header and cells
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Makes the table sortable by this column
+    pub fn sort_by<F: Fn(&T, &T) -> Ordering + Send + Sync + 'static>(
+        mut self,
+        compare: F,
+    ) -> Self {
+        self.compare = Some(Arc::new(compare));
+        self
+    }
+
+    /// The header text, with an indicator if the table is sorted by this column
+    fn header(&self, sorted: Option<bool>) -> String {
+        match sorted {
+            Some(false) => format!("{} ▲", self.title),
+            Some(true) => format!("{} ▼", self.title),
+            None => self.title.clone(),
+        }
+    }
+}
+
+/// Scroll state of a table
+#[derive(Debug, Clone, Default)]
+struct Viewport {
+    /// Index of the first visible row
+    offset: usize,
+    /// Selection at the last render, to scroll only when it changes
+    selected: Option<usize>,
+    /// Number of visible rows at the last render
+    page: usize,
+}
+
+/// Sort order and column widths kept between renders
+///
+/// Cleared when the rows change or the table re-renders, and otherwise
+/// recomputed only when the sort order or the table width changes.
+#[derive(Debug, Clone, Default)]
+struct Cache {
+    /// Row indices in display order, and the sort order they are for
+    order: Option<(Option<SortOrder>, Vec<usize>)>,
+    /// Width of the widest cell of every column, header included
+    content: Option<Vec<u16>>,
+    /// Resolved column widths, and the table width they are for
+    widths: Option<(u16, Vec<u16>)>,
+}
+
+impl Cache {
+    /// Returns the row indices in display order for `sort`
+    fn order<T>(&mut self, rows: &[T], columns: &[Column<T>], sort: Option<SortOrder>) -> &[usize] {
+        if self.order.as_ref().is_none_or(|(key, _)| *key != sort) {
+            self.order = Some((sort, display_order(rows, columns, sort)));
+        }
+        &self.order.as_ref().unwrap().1
+    }
+
+    /// Returns the column widths for a table of the given width
+    fn widths<T>(&mut self, rows: &[T], columns: &[Column<T>], width: u16) -> &[u16] {
+        if self.widths.as_ref().is_none_or(|(key, _)| *key != width) {
+            let content = self
+                .content
+                .get_or_insert_with(|| content_widths(columns, rows));
+            self.widths = Some((width, column_widths(columns, content, width)));
+        }
+        &self.widths.as_ref().unwrap().1
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            title: self.title.clone(),
+            width: self.width,
+            align: self.align,
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+/// Returns the row indices in display order
+fn display_order<T>(rows: &[T], columns: &[Column<T>], sort: Option<SortOrder>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+
+    if let Some(sort) = sort {
+        if let Some(compare) = columns.get(sort.column).and_then(|c| c.compare.as_ref()) {
+            order.sort_by(|a, b| {
+                let ordering = compare(&rows[*a], &rows[*b]);
+                if sort.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+    }
+
+    order
+}
+
+/// Measures the widest cell of every `Auto` column, header included
+///
+/// Other columns do not depend on their cells and measure as zero.
+fn content_widths<T>(columns: &[Column<T>], rows: &[T]) -> Vec<u16> {
+    columns
+        .iter()
+        .map(|column| match column.width {
+            ColumnWidth::Auto => rows
+                .iter()
+                .map(|row| text_size(&(column.cell)(row)).width)
+                .chain([text_size(&column.header(column.compare.as_ref().map(|_| false))).width])
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        })
+        .collect()
+}
+
+/// Resolves the width of every column for a table of the given width
+///
+/// `content` holds the measured widths from `content_widths`. Columns are
+/// separated by a single space. When the columns do not fit, the widest
+/// `Auto` columns are narrowed first, then `Percent` columns.
+fn column_widths<T>(columns: &[Column<T>], content: &[u16], width: u16) -> Vec<u16> {
+    let available = width.saturating_sub(columns.len().saturating_sub(1) as u16);
+
+    let mut widths: Vec<u16> = columns
+        .iter()
+        .zip(content)
+        .map(|(column, content)| match column.width {
+            ColumnWidth::Fixed(width) => width,
+            ColumnWidth::Percent(percent) => (available as u32 * percent as u32 / 100) as u16,
+            ColumnWidth::Auto => *content,
+        })
+        .collect();
+
+    let mut overflow = widths
+        .iter()
+        .map(|w| *w as u32)
+        .sum::<u32>()
+        .saturating_sub(available as u32);
+
+    let shrinkable: [fn(&ColumnWidth) -> bool; 2] = [
+        |width| matches!(width, ColumnWidth::Auto),
+        |width| matches!(width, ColumnWidth::Percent(_)),
+    ];
+    for is_shrinkable in shrinkable {
+        while overflow > 0 {
+            let widest = (0..columns.len())
+                .filter(|i| is_shrinkable(&columns[*i].width) && widths[*i] > 0)
+                .max_by_key(|i| widths[*i]);
+
+            let Some(widest) = widest else {
+                break;
+            };
+            widths[widest] -= 1;
+            overflow -= 1;
+        }
+    }
+
+    widths
+}
+
+/// Fits the first line of `text` into `width` columns
+///
+/// Longer text is truncated with an ellipsis, shorter text is padded
+/// according to the alignment.
+fn fit(text: &str, width: u16, align: Alignment) -> String {
+    let text = text.lines().next().unwrap_or("");
+
+    if display_width(text) > width {
+        return match width {
+            0 => String::new(),
+            _ => {
+                let text = format!("{}…", truncate(text, width - 1));
+                let padding = width.saturating_sub(display_width(&text)) as usize;
+                format!("{text}{}", " ".repeat(padding))
+            }
+        };
+    }
+
+    let padding = (width - display_width(text)) as usize;
+    let (left, right) = match align {
+        Alignment::Left | Alignment::Justify => (0, padding),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::Right => (padding, 0),
+    };
+
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+}
+
+/// Joins the cells of a row into a single line
+fn format_row<T>(
+    cells: impl Iterator<Item = String>,
+    widths: &[u16],
+    columns: &[Column<T>],
+) -> String {
+    cells
+        .zip(widths)
+        .zip(columns)
+        .map(|((text, width), column)| fit(&text, *width, column.align))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl<T: Send + Sync + 'static> ComponentImpl for Table<T> {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        use_focus(cx);
+
+        let viewport = use_hook(|| Arc::new(Mutex::new(Viewport::default())));
+
+        let mut created = false;
+        let cache = use_hook(|| {
+            created = true;
+            Arc::new(Mutex::new(Cache::default()))
+        });
+
+        if created {
+            let hook = HookEffect::new({
+                let cache = cache.clone();
+                move || *cache.lock().unwrap() = Cache::default()
+            });
+            self.rows.on_update(hook.clone());
+            cx.on_unmount(move || hook.cancel());
+        } else {
+            // The columns may have changed
+            *cache.lock().unwrap() = Cache::default();
+        }
+
+        cx.on_event({
+            let rows = self.rows.clone();
+            let selected = self.selected.clone();
+            let sort = self.sort.clone();
+            let columns = self.columns.clone();
+            let viewport = viewport.clone();
+            let cache = cache.clone();
+            move |cx, key: &KeyEvent| {
+                if !cx.is_focused() || key.kind == KeyEventKind::Release {
+                    return;
+                }
+
+                let len = rows.get().len();
+                let page = viewport.lock().unwrap().page.max(1);
+                let current = selected.get_dl();
+
+                let next = match key.code {
+                    KeyCode::Up => current.saturating_sub(1),
+                    KeyCode::Down => current + 1,
+                    KeyCode::PageUp => current.saturating_sub(page),
+                    KeyCode::PageDown => current + page,
+                    KeyCode::Home => 0,
+                    KeyCode::End => len.saturating_sub(1),
+                    KeyCode::Char(c @ '1'..='9') => {
+                        let column = c as usize - '1' as usize;
+                        if columns.get(column).is_some_and(|c| c.compare.is_some()) {
+                            let previous = sort.get_dl();
+                            let descending =
+                                previous.is_some_and(|s| s.column == column && !s.descending);
+                            let next = Some(SortOrder { column, descending });
+
+                            // Keep the same row selected in the new order
+                            let rows = rows.get();
+                            let mut cache = cache.lock().unwrap();
+                            let row = cache.order(&rows, &columns, previous).get(current).copied();
+                            let moved = row.and_then(|row| {
+                                cache
+                                    .order(&rows, &columns, next)
+                                    .iter()
+                                    .position(|index| *index == row)
+                            });
+                            drop(cache);
+                            drop(rows);
+
+                            sort.set(next);
+                            if let Some(moved) = moved.filter(|moved| *moved != current) {
+                                selected.set(moved);
+                            }
+                        }
+                        return;
+                    }
+                    _ => return,
+                }
+                .min(len.saturating_sub(1));
+
+                if next != current {
+                    selected.set(next);
+                }
+            }
+        });
+
+        let columns = self.columns.clone();
+        let rows = self.rows.clone();
+        let selected = self.selected.clone();
+        let sort = self.sort.clone();
+
+        Arc::new(move |ctx| {
+            let rows = rows.get();
+            let sort = sort.get_dl();
+            let mut cache = cache.lock().unwrap();
+            let widths = cache.widths(&rows, &columns, ctx.area.width).to_vec();
+            let height = (ctx.area.height as usize).saturating_sub(1);
+            let selected = selected.get_dl().min(rows.len().saturating_sub(1));
+
+            let header = format_row(
+                columns.iter().enumerate().map(|(i, column)| {
+                    column.header(sort.filter(|s| s.column == i).map(|s| s.descending))
+                }),
+                &widths,
+                &columns,
+            );
+            ctx.draw_styled_text(Point { x: 0, y: 0 }, &header, Style::new().bold());
+
+            let mut viewport = viewport.lock().unwrap();
+            viewport.page = height;
+
+            // Follow the selection only when it moved
+            if viewport.selected != Some(selected) {
+                if selected < viewport.offset {
+                    viewport.offset = selected;
+                } else if height > 0 && selected >= viewport.offset + height {
+                    viewport.offset = selected + 1 - height;
+                }
+                viewport.selected = Some(selected);
+            }
+            viewport.offset = viewport.offset.min(rows.len().saturating_sub(height));
+
+            let order = cache.order(&rows, &columns, sort);
+
+            for (y, index) in order.iter().enumerate().skip(viewport.offset).take(height) {
+                let row = &rows[*index];
+                let line = format_row(columns.iter().map(|c| (c.cell)(row)), &widths, &columns);
+                let point = Point {
+                    x: 0,
+                    y: (y - viewport.offset + 1) as u16,
+                };
+
+                if y == selected {
+                    ctx.draw_styled_text(point, &line, Style::new().reverse());
+                } else {
+                    ctx.draw_text(point, &line);
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,414 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use unicode_width::UnicodeWidthChar;
+
+use crate::component_prelude::*;
+use crate::engine::events::Paste;
+
+/// Maximum number of undo steps kept by a text area
+const HISTORY_LIMIT: usize = 100;
+
+/// Multi-line text editor bound to a `State<Vec<String>>`, one string per line
+///
+/// Lines wider than the area are wrapped and the view scrolls to keep the
+/// cursor visible. The editor is focusable and only reacts to keys while
+/// focused.
+///
+/// # Keys
+/// - Arrows, `Home`/`End`, `PageUp`/`PageDown`: move the cursor
+///   (`Up`/`Down` keep the preferred column, `Ctrl+Home`/`Ctrl+End` jump to
+///   the start/end of the text)
+/// - `Shift` + movement: extend the selection, `Ctrl+A`: select all
+/// - `Backspace`/`Delete`: delete the selection or a character
+/// - `Enter`: split the line
+/// - `Ctrl+Z`: undo, `Ctrl+Y`: redo
+///
+/// Pasted text (bracketed paste) is inserted at the cursor.
+pub struct TextArea {
+    /// The edited lines
+    pub value: State<Vec<String>>,
+    /// Text shown while the value is empty
+    pub placeholder: String,
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self {
+            value: State::new(vec![String::new()]),
+            placeholder: String::new(),
+        }
+    }
+}
+
+/// A position in the text: (line, character)
+type Position = (usize, usize);
+
+/// A visual row: (line, first character, end character)
+type Row = (usize, usize, usize);
+
+/// A snapshot used for undo and redo
+type Snapshot = (Vec<Vec<char>>, Position);
+
+/// Cursor, selection and history state of a text area
+#[derive(Debug, Clone, Default)]
+struct Editor {
+    /// Cursor position
+    cursor: Position,
+    /// Start of the selection, if any; the cursor is the other end
+    anchor: Option<Position>,
+    /// Column kept when moving up and down through shorter rows
+    preferred: Option<usize>,
+    /// States to return to on undo
+    undo: Vec<Snapshot>,
+    /// States to return to on redo
+    redo: Vec<Snapshot>,
+    /// Width of the area at the last render
+    width: usize,
+    /// Height of the area at the last render
+    height: usize,
+    /// First visible row
+    scroll: usize,
+}
+
+/// Display width of a character
+fn char_width(c: &char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Splits lines into visual rows of at most `width` columns
+///
+/// Every line gets room for the cursor after its last character.
+fn layout(lines: &[Vec<char>], width: usize) -> Vec<Row> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let mut start = 0;
+        let mut used = 0;
+
+        for (col, c) in line.iter().enumerate() {
+            if used + char_width(c) > width && col > start {
+                rows.push((i, start, col));
+                start = col;
+                used = 0;
+            }
+            used += char_width(c);
+        }
+
+        if used >= width && start < line.len() {
+            rows.push((i, start, line.len()));
+            start = line.len();
+        }
+        rows.push((i, start, line.len()));
+    }
+
+    rows
+}
+
+/// Finds the visual row and column of a position
+fn locate(rows: &[Row], (line, col): Position) -> (usize, usize) {
+    rows.iter()
+        .enumerate()
+        .rfind(|(_, (l, start, _))| *l == line && *start <= col)
+        .map(|(i, (_, start, _))| (i, col - start))
+        .unwrap_or((0, 0))
+}
+
+impl Editor {
+    fn selection(&self) -> Option<(Position, Position)> {
+        self.anchor
+            .filter(|anchor| *anchor != self.cursor)
+            .map(|anchor| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    /// Keeps the cursor and selection inside the text after external changes
+    fn clamp(&mut self, lines: &[Vec<char>]) {
+        let line = self.cursor.0.min(lines.len() - 1);
+        self.cursor = (line, self.cursor.1.min(lines[line].len()));
+        self.anchor = self
+            .anchor
+            .filter(|(l, c)| *l < lines.len() && *c <= lines[*l].len());
+    }
+
+    fn move_to(&mut self, position: Position, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+    }
+
+    /// Moves the cursor by a number of visual rows, keeping the preferred column
+    fn move_rows(&mut self, lines: &[Vec<char>], delta: isize, select: bool) {
+        let rows = layout(lines, self.width);
+        let (row, col) = locate(&rows, self.cursor);
+        let column = *self.preferred.get_or_insert(col);
+        let target = (row as isize + delta).clamp(0, rows.len() as isize - 1) as usize;
+        let (line, start, end) = rows[target];
+
+        // Stay on this row rather than wrapping onto the next one
+        let last = rows.get(target + 1).is_none_or(|(l, _, _)| *l != line);
+        let max = if last { end } else { end.saturating_sub(1) };
+
+        self.move_to((line, (start + column).min(max)), select);
+        self.preferred = Some(column);
+    }
+
+    fn save(&mut self, lines: &[Vec<char>]) {
+        self.undo.push((lines.to_vec(), self.cursor));
+        if self.undo.len() > HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    fn delete_selection(&mut self, lines: &mut Vec<Vec<char>>) -> bool {
+        let Some(((start_line, start_col), (end_line, end_col))) = self.selection() else {
+            return false;
+        };
+
+        let tail = lines[end_line].split_off(end_col);
+        lines.drain(start_line + 1..=end_line);
+        lines[start_line].truncate(start_col);
+        lines[start_line].extend(tail);
+
+        self.cursor = (start_line, start_col);
+        self.anchor = None;
+        true
+    }
+
+    fn insert(&mut self, lines: &mut Vec<Vec<char>>, text: &str) {
+        self.delete_selection(lines);
+
+        let (line, col) = self.cursor;
+        let tail = lines[line].split_off(col);
+        let mut current = line;
+
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                current += 1;
+                lines.insert(current, Vec::new());
+            }
+            lines[current].extend(part.chars().filter(|c| !c.is_control()));
+        }
+
+        self.cursor = (current, lines[current].len());
+        lines[current].extend(tail);
+    }
+
+    fn handle_key(&mut self, lines: &mut Vec<Vec<char>>, key: &KeyEvent) {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        self.clamp(lines);
+        let (line, col) = self.cursor;
+        let last = lines.len() - 1;
+
+        if !matches!(
+            key.code,
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
+        ) {
+            self.preferred = None;
+        }
+
+        match key.code {
+            KeyCode::Up => self.move_rows(lines, -1, shift),
+            KeyCode::Down => self.move_rows(lines, 1, shift),
+            KeyCode::PageUp => self.move_rows(lines, -(self.height.max(1) as isize), shift),
+            KeyCode::PageDown => self.move_rows(lines, self.height.max(1) as isize, shift),
+            KeyCode::Left if col > 0 => self.move_to((line, col - 1), shift),
+            KeyCode::Left if line > 0 => self.move_to((line - 1, lines[line - 1].len()), shift),
+            KeyCode::Left => self.move_to(self.cursor, shift),
+            KeyCode::Right if col < lines[line].len() => self.move_to((line, col + 1), shift),
+            KeyCode::Right if line < last => self.move_to((line + 1, 0), shift),
+            KeyCode::Right => self.move_to(self.cursor, shift),
+            KeyCode::Home if ctrl => self.move_to((0, 0), shift),
+            KeyCode::Home => self.move_to((line, 0), shift),
+            KeyCode::End if ctrl => self.move_to((last, lines[last].len()), shift),
+            KeyCode::End => self.move_to((line, lines[line].len()), shift),
+            KeyCode::Char('a') if ctrl => {
+                self.anchor = Some((0, 0));
+                self.cursor = (last, lines[last].len());
+            }
+            KeyCode::Char('z') if ctrl => {
+                if let Some((previous, cursor)) = self.undo.pop() {
+                    self.redo
+                        .push((std::mem::replace(lines, previous), self.cursor));
+                    self.cursor = cursor;
+                    self.anchor = None;
+                }
+            }
+            KeyCode::Char('y') if ctrl => {
+                if let Some((next, cursor)) = self.redo.pop() {
+                    self.undo
+                        .push((std::mem::replace(lines, next), self.cursor));
+                    self.cursor = cursor;
+                    self.anchor = None;
+                }
+            }
+            KeyCode::Backspace | KeyCode::Delete if self.selection().is_some() => {
+                self.save(lines);
+                self.delete_selection(lines);
+            }
+            KeyCode::Backspace if col > 0 => {
+                self.save(lines);
+                lines[line].remove(col - 1);
+                self.cursor = (line, col - 1);
+            }
+            KeyCode::Backspace if line > 0 => {
+                self.save(lines);
+                let removed = lines.remove(line);
+                self.cursor = (line - 1, lines[line - 1].len());
+                lines[line - 1].extend(removed);
+            }
+            KeyCode::Delete if col < lines[line].len() => {
+                self.save(lines);
+                lines[line].remove(col);
+            }
+            KeyCode::Delete if line < last => {
+                self.save(lines);
+                let removed = lines.remove(line + 1);
+                lines[line].extend(removed);
+            }
+            KeyCode::Enter => {
+                self.save(lines);
+                self.insert(lines, "\n");
+            }
+            KeyCode::Char(c) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+                self.save(lines);
+                self.insert(lines, &c.to_string());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Converts the state's lines into editable character vectors
+fn to_chars(value: &State<Vec<String>>) -> Vec<Vec<char>> {
+    let mut lines: Vec<Vec<char>> = value.get().iter().map(|l| l.chars().collect()).collect();
+    if lines.is_empty() {
+        lines.push(Vec::new());
+    }
+    lines
+}
+
+fn from_chars(lines: Vec<Vec<char>>) -> Vec<String> {
+    lines.into_iter().map(|l| l.into_iter().collect()).collect()
+}
+
+impl ComponentImpl for TextArea {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        let focused = use_focus(cx);
+        let editor = use_hook(|| Arc::new(Mutex::new(Editor::default())));
+
+        cx.on_event({
+            let value = self.value.clone();
+            let editor = editor.clone();
+            move |cx, key: &KeyEvent| {
+                if !cx.is_focused() || key.kind == KeyEventKind::Release {
+                    return;
+                }
+
+                let mut lines = to_chars(&value);
+                let before = lines.clone();
+                editor.lock().unwrap().handle_key(&mut lines, key);
+
+                if lines != before {
+                    value.set(from_chars(lines));
+                } else {
+                    cx.request_redraw();
+                }
+            }
+        });
+
+        cx.on_event({
+            let value = self.value.clone();
+            let editor = editor.clone();
+            move |cx, Paste(text): &Paste| {
+                if !cx.is_focused() {
+                    return;
+                }
+
+                let mut lines = to_chars(&value);
+                let mut editor = editor.lock().unwrap();
+                editor.clamp(&lines);
+                editor.save(&lines);
+                editor.insert(&mut lines, &text.replace("\r\n", "\n").replace('\r', "\n"));
+                value.set(from_chars(lines));
+            }
+        });
+
+        let value = self.value.clone();
+        let placeholder = self.placeholder.clone();
+
+        Arc::new(move |ctx| {
+            let (width, height) = (ctx.area.width as usize, ctx.area.height as usize);
+            let is_focused = focused.get_dl();
+            let lines = to_chars(&value);
+
+            let mut editor = editor.lock().unwrap();
+            editor.width = width;
+            editor.height = height;
+
+            editor.clamp(&lines);
+
+            if width == 0 || height == 0 {
+                return;
+            }
+
+            if lines.len() == 1 && lines[0].is_empty() && !placeholder.is_empty() {
+                for (y, text) in placeholder.lines().take(height).enumerate() {
+                    ctx.draw_styled_text(
+                        Point { x: 0, y: y as u16 },
+                        &text.chars().take(width).collect::<String>(),
+                        Style::new().fg(Color::DarkGrey),
+                    );
+                }
+            }
+
+            let rows = layout(&lines, width);
+            let (cursor_row, _) = locate(&rows, editor.cursor);
+
+            // Keep the cursor row inside the visible window
+            if cursor_row < editor.scroll {
+                editor.scroll = cursor_row;
+            } else if cursor_row >= editor.scroll + height {
+                editor.scroll = cursor_row + 1 - height;
+            }
+
+            let selection = editor.selection();
+            let cursor = editor.cursor;
+            let highlighted = |position: Position| {
+                (is_focused && position == cursor)
+                    || selection.is_some_and(|(start, end)| position >= start && position < end)
+            };
+
+            for (y, (line, start, end)) in rows.iter().skip(editor.scroll).take(height).enumerate()
+            {
+                let row = &lines[*line][*start..*end];
+                let text: String = row.iter().collect();
+                ctx.draw_text(Point { x: 0, y: y as u16 }, &text);
+
+                // The cursor cell after the end of a line, or a selected line break
+                let row_width: usize = row.iter().map(char_width).sum();
+                let stop = if *end == lines[*line].len() && row_width < width {
+                    end + 1
+                } else {
+                    *end
+                };
+
+                let mut x = 0;
+                for col in *start..stop {
+                    let c = lines[*line].get(col).copied().unwrap_or(' ');
+                    if highlighted((*line, col)) {
+                        ctx.draw_styled_text(
+                            Point { x, y: y as u16 },
+                            &c.to_string(),
+                            Style::new().reverse(),
+                        );
+                    }
+                    x += char_width(&c) as u16;
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,326 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use unicode_width::UnicodeWidthChar;
+
+use crate::component_prelude::*;
+use crate::engine::events::Paste;
+
+/// Callback invoked with the input's value when Enter is pressed
+pub type SubmitHandler = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Single-line text input bound to a `State<String>`
+///
+/// The input is focusable and only reacts to keys while focused.
+///
+/// # Keys
+/// - `Left`/`Right`, `Home`/`End`: move the cursor (`Ctrl` jumps by word)
+/// - `Shift` + movement: extend the selection, `Ctrl+A`: select all
+/// - `Backspace`/`Delete`: delete the selection or a character (`Ctrl` deletes a word)
+/// - `Insert`: toggle between insert and overwrite mode
+/// - `Enter`: call `on_submit`
+///
+/// # Example
+///
+/// ```rust,ignore
+/// rsx! {
+///     TextInput {
+///         value: name.clone(),
+///         placeholder: "Name".to_string(),
+///         ..Default::default()
+///     }
+/// }
+/// ```
+pub struct TextInput {
+    /// The edited text
+    pub value: State<String>,
+    /// Text shown while the value is empty
+    pub placeholder: String,
+    /// Character displayed in place of each character of the value
+    pub mask: Option<char>,
+    /// Maximum number of characters
+    pub max_length: Option<usize>,
+    /// Called with the value when Enter is pressed
+    pub on_submit: Option<SubmitHandler>,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            value: State::new(String::new()),
+            placeholder: String::new(),
+            mask: None,
+            max_length: None,
+            on_submit: None,
+        }
+    }
+}
+
+/// Cursor and selection state of a text input
+#[derive(Debug, Clone, Default)]
+struct Editor {
+    /// Cursor position, in characters
+    cursor: usize,
+    /// Start of the selection, if any; the cursor is the other end
+    anchor: Option<usize>,
+    /// Whether typing replaces the character under the cursor
+    overwrite: bool,
+}
+
+impl Editor {
+    fn selection(&self) -> Option<(usize, usize)> {
+        self.anchor
+            .filter(|anchor| *anchor != self.cursor)
+            .map(|anchor| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    fn move_to(&mut self, position: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+    }
+
+    fn delete_selection(&mut self, chars: &mut Vec<char>) -> bool {
+        match self.selection() {
+            Some((start, end)) => {
+                chars.drain(start..end);
+                self.cursor = start;
+                self.anchor = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, chars: &mut Vec<char>, text: &str, max_length: Option<usize>) {
+        self.delete_selection(chars);
+
+        for c in text.chars().filter(|c| !c.is_control()) {
+            if self.overwrite && self.cursor < chars.len() {
+                chars[self.cursor] = c;
+            } else if max_length.is_none_or(|max| chars.len() < max) {
+                chars.insert(self.cursor, c);
+            } else {
+                break;
+            }
+            self.cursor += 1;
+        }
+    }
+
+    /// Handles a key press, returning whether the value was submitted
+    fn handle_key(
+        &mut self,
+        chars: &mut Vec<char>,
+        key: &KeyEvent,
+        max_length: Option<usize>,
+    ) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+
+        self.cursor = self.cursor.min(chars.len());
+
+        match key.code {
+            KeyCode::Left if ctrl => self.move_to(word_start(chars, self.cursor), shift),
+            KeyCode::Left => self.move_to(self.cursor.saturating_sub(1), shift),
+            KeyCode::Right if ctrl => self.move_to(word_end(chars, self.cursor), shift),
+            KeyCode::Right => self.move_to((self.cursor + 1).min(chars.len()), shift),
+            KeyCode::Home => self.move_to(0, shift),
+            KeyCode::End => self.move_to(chars.len(), shift),
+            KeyCode::Backspace | KeyCode::Delete if self.delete_selection(chars) => {}
+            KeyCode::Backspace => {
+                let start = if ctrl {
+                    word_start(chars, self.cursor)
+                } else {
+                    self.cursor.saturating_sub(1)
+                };
+                chars.drain(start..self.cursor);
+                self.cursor = start;
+            }
+            KeyCode::Delete => {
+                let end = if ctrl {
+                    word_end(chars, self.cursor)
+                } else {
+                    (self.cursor + 1).min(chars.len())
+                };
+                chars.drain(self.cursor..end);
+            }
+            KeyCode::Insert => self.overwrite = !self.overwrite,
+            KeyCode::Enter => return true,
+            KeyCode::Char('a') if ctrl => {
+                self.anchor = Some(0);
+                self.cursor = chars.len();
+            }
+            KeyCode::Char(c) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+                self.insert(chars, &c.to_string(), max_length)
+            }
+            _ => {}
+        }
+
+        false
+    }
+}
+
+/// Finds the start of the word before `position`
+fn word_start(chars: &[char], position: usize) -> usize {
+    let mut i = position;
+    while i > 0 && chars[i - 1].is_whitespace() {
+        i -= 1;
+    }
+    while i > 0 && !chars[i - 1].is_whitespace() {
+        i -= 1;
+    }
+    i
+}
+
+/// Finds the end of the word after `position`
+fn word_end(chars: &[char], position: usize) -> usize {
+    let mut i = position;
+    while i < chars.len() && chars[i].is_whitespace() {
+        i += 1;
+    }
+    while i < chars.len() && !chars[i].is_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+impl ComponentImpl for TextInput {
+    fn call(&self, cx: &Arc<Context>) -> View {
+        let focused = use_focus(cx);
+        let editor = use_hook(|| {
+            Arc::new(Mutex::new(Editor {
+                cursor: self.value.get().chars().count(),
+                ..Default::default()
+            }))
+        });
+        // First visible character
+        let scroll = use_hook(|| Arc::new(Mutex::new(0usize)));
+
+        cx.on_event({
+            let value = self.value.clone();
+            let editor = editor.clone();
+            let max_length = self.max_length;
+            let on_submit = self.on_submit.clone();
+            move |cx, key: &KeyEvent| {
+                if !cx.is_focused() || key.kind == KeyEventKind::Release {
+                    return;
+                }
+
+                let mut chars: Vec<char> = value.get_dl().chars().collect();
+                let before = chars.clone();
+                let submitted = editor
+                    .lock()
+                    .unwrap()
+                    .handle_key(&mut chars, key, max_length);
+
+                if chars != before {
+                    value.set(chars.into_iter().collect());
+                } else {
+                    cx.request_redraw();
+                }
+
+                if submitted {
+                    if let Some(on_submit) = &on_submit {
+                        on_submit(&value.get_dl());
+                    }
+                }
+            }
+        });
+
+        cx.on_event({
+            let value = self.value.clone();
+            let editor = editor.clone();
+            let max_length = self.max_length;
+            move |cx, Paste(text): &Paste| {
+                if !cx.is_focused() {
+                    return;
+                }
+
+                let mut chars: Vec<char> = value.get_dl().chars().collect();
+                editor.lock().unwrap().insert(&mut chars, text, max_length);
+                value.set(chars.into_iter().collect());
+            }
+        });
+
+        let value = self.value.clone();
+        let placeholder = self.placeholder.clone();
+        let mask = self.mask;
+
+        Arc::new(move |ctx| {
+            let width = ctx.area.width as usize;
+            let is_focused = focused.get_dl();
+            let chars: Vec<char> = value.get_dl().chars().map(|c| mask.unwrap_or(c)).collect();
+
+            let mut editor = editor.lock().unwrap();
+            editor.cursor = editor.cursor.min(chars.len());
+
+            if chars.is_empty() && !placeholder.is_empty() {
+                ctx.draw_styled_text(
+                    Point { x: 0, y: 0 },
+                    &placeholder.chars().take(width).collect::<String>(),
+                    Style::new().fg(Color::DarkGrey),
+                );
+            }
+
+            if width == 0 {
+                return;
+            }
+
+            // Display width of a character, the cursor past the end taking one column
+            let column_width = |i: usize| chars.get(i).map_or(1, |c| c.width().unwrap_or(0));
+
+            // Keep the cursor inside the visible window
+            let mut scroll = scroll.lock().unwrap();
+            *scroll = (*scroll).min(editor.cursor);
+            while *scroll < editor.cursor
+                && (*scroll..=editor.cursor).map(column_width).sum::<usize>() > width
+            {
+                *scroll += 1;
+            }
+
+            let selection = editor.selection();
+            let highlighted = |i: usize| {
+                (is_focused && i == editor.cursor)
+                    || selection.is_some_and(|(start, end)| i >= start && i < end)
+            };
+
+            // Draw runs of equally styled characters, plus the cursor past the end
+            let cursor_fill = match chars.is_empty() {
+                true => placeholder.chars().next().unwrap_or(' '),
+                false => ' ',
+            };
+            let mut end = *scroll;
+            let mut columns = 0;
+            while end <= chars.len() && columns + column_width(end) <= width {
+                columns += column_width(end);
+                end += 1;
+            }
+
+            let mut start = *scroll;
+            let mut x = 0;
+            while start < end {
+                let reverse = highlighted(start);
+                let mut stop = start + 1;
+                while stop < end && highlighted(stop) == reverse {
+                    stop += 1;
+                }
+
+                let point = Point { x, y: 0 };
+                x += (start..stop).map(column_width).sum::<usize>() as u16;
+
+                if reverse {
+                    let text: String = (start..stop)
+                        .map(|i| chars.get(i).copied().unwrap_or(cursor_fill))
+                        .collect();
+                    ctx.draw_styled_text(point, &text, Style::new().reverse());
+                } else if start < chars.len() {
+                    let text: String = chars[start..stop.min(chars.len())].iter().collect();
+                    ctx.draw_text(point, &text);
+                }
+
+                start = stop;
+            }
+        })
+    }
+}
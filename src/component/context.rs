@@ -6,21 +6,27 @@
 
 use std::{
     any::{Any, TypeId},
-    collections::HashMap,
-    sync::{Arc, Mutex},
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, Weak},
 };
 
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
 use access_cell::AccessCell;
 
 use crate::{
-    component::EventHandler,
-    engine::{Command, CommandExecutor},
-    hooks::{use_effect, HookDependency},
+    component::{EventCtx, EventHandler, EventPhase},
+    engine::{batch, request_redraw, Command, CommandExecutor, EffectScheduler},
+    hooks::{effect, rendering, HookDependency, HookSlots},
     render::DrawContext,
     View, ViewWrapper,
 };
 
-use super::{scope::Scope, Component, ComponentImpl};
+use super::{
+    focus::{focus_order, FocusManager},
+    scope::Scope,
+    Component, ComponentImpl,
+};
 
 /// Context represents the runtime state and behavior of a component
 ///
@@ -29,47 +35,136 @@ use super::{scope::Scope, Component, ComponentImpl};
 /// - The current view (render result)
 /// - Event handlers for responding to events
 /// - Child scopes for managing child components
+/// - A link to its parent context and the tree's focus manager
+/// - The values of the hooks called by the component
+/// - Values provided to its descendants
 pub struct Context {
     /// The component implementation
     component: AccessCell<Component>,
+    /// Type of the component implementation
+    kind: TypeId,
     /// The current rendered view
     view: AccessCell<View>,
     /// Event handlers grouped by event type
     event_handlers: AccessCell<HashMap<TypeId, Vec<EventHandler>>>,
+    /// Capture phase event handlers grouped by event type
+    capture_handlers: AccessCell<HashMap<TypeId, Vec<EventHandler>>>,
     /// Child scopes (component hierarchies)
     pub(crate) scopes: Mutex<Vec<Arc<Scope>>>,
+    /// Child scopes of the previous render, reused in order by the next ones
+    previous_scopes: Mutex<VecDeque<Arc<Scope>>>,
     /// Command executor for this context's command handling
     executor: Arc<dyn CommandExecutor>,
+    /// Scheduler running the effects of the component tree, if any
+    scheduler: Option<Arc<EffectScheduler>>,
+    /// The context owning the scope this context was created in
+    parent: Option<Weak<Context>>,
+    /// Focus state shared by the whole component tree
+    focus: Arc<FocusManager>,
+    /// Whether this component can receive focus
+    focusable: Mutex<bool>,
+    /// Values of the hooks called by the component, kept across renders
+    pub(crate) hooks: Mutex<HookSlots>,
+    /// Functions to run when the component unmounts
+    unmount_handlers: Mutex<Vec<Box<dyn FnOnce() + Send>>>,
+    /// Values provided to descendants, by type
+    provided: Mutex<HashMap<TypeId, Provided>>,
 }
 
+/// Value provided by a component to its descendants
+type Provided = Arc<dyn Any + Send + Sync>;
+
 impl Context {
-    /// Creates a new context for the given component
+    /// Creates a new root context for the given component
+    ///
+    /// Without a scheduler, the effects of the tree run on worker threads
+    /// (see `EffectScheduler::background`).
     pub fn new<F: ComponentImpl + 'static>(
         component: F,
         executor: Arc<dyn CommandExecutor>,
+    ) -> Arc<Self> {
+        Self::new_root(component, executor, None)
+    }
+
+    /// Creates a new root context whose effects run on the given scheduler
+    ///
+    /// Engines pass their own scheduler, which they run before every frame.
+    pub fn with_scheduler<F: ComponentImpl + 'static>(
+        component: F,
+        executor: Arc<dyn CommandExecutor>,
+        scheduler: Arc<EffectScheduler>,
+    ) -> Arc<Self> {
+        Self::new_root(component, executor, Some(scheduler))
+    }
+
+    /// Creates a new root context
+    fn new_root<F: ComponentImpl + 'static>(
+        component: F,
+        executor: Arc<dyn CommandExecutor>,
+        scheduler: Option<Arc<EffectScheduler>>,
     ) -> Arc<Self> {
         Arc::new(Self {
             component: AccessCell::new(Arc::new(component)),
+            kind: TypeId::of::<F>(),
             view: AccessCell::new(Arc::new(|_| {})),
             event_handlers: AccessCell::new(HashMap::new()),
+            capture_handlers: AccessCell::new(HashMap::new()),
             scopes: Mutex::new(Vec::new()),
+            previous_scopes: Mutex::new(VecDeque::new()),
             executor,
+            scheduler,
+            parent: None,
+            focus: FocusManager::new(),
+            focusable: Mutex::new(false),
+            hooks: Mutex::new(HookSlots::default()),
+            unmount_handlers: Mutex::new(Vec::new()),
+            provided: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Creates a new context for a child component of `parent`
+    ///
+    /// The child shares the parent's command executor, effect scheduler
+    /// and focus manager.
+    pub(crate) fn new_child<F: ComponentImpl + 'static>(
+        component: F,
+        parent: &Arc<Context>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            component: AccessCell::new(Arc::new(component)),
+            kind: TypeId::of::<F>(),
+            view: AccessCell::new(Arc::new(|_| {})),
+            event_handlers: AccessCell::new(HashMap::new()),
+            capture_handlers: AccessCell::new(HashMap::new()),
+            scopes: Mutex::new(Vec::new()),
+            previous_scopes: Mutex::new(VecDeque::new()),
+            executor: parent.executor.clone(),
+            scheduler: parent.scheduler.clone(),
+            parent: Some(Arc::downgrade(parent)),
+            focus: parent.focus.clone(),
+            focusable: Mutex::new(false),
+            hooks: Mutex::new(HookSlots::default()),
+            unmount_handlers: Mutex::new(Vec::new()),
+            provided: Mutex::new(HashMap::new()),
         })
     }
 
     /// Refreshes the component by re-rendering it
     ///
     /// Clears event handlers and calls the component to produce a new view.
+    /// Hooks called by the component keep their values, and the children
+    /// of the previous render are reused by the new ones.
     pub fn refresh(self: &Arc<Self>) {
-        self.event_handlers
-            .access(|event_handlers| event_handlers.clear());
         self.component.access({
             let s = self.clone();
             move |component| {
                 let component = component.clone();
                 s.view.access({
                     let s = s.clone();
-                    move |view| *view = component.call(&s)
+                    move |view| {
+                        *view = s.render(&component);
+                        s.request_redraw();
+                    }
                 })
             }
         });
@@ -81,9 +176,6 @@ impl Context {
     pub fn refresh_sync(self: &Arc<Self>) {
         let (tx, rx) = std::sync::mpsc::channel::<()>();
 
-        self.event_handlers
-            .access(|event_handlers| event_handlers.clear());
-
         self.component.access({
             let s = self.clone();
             move |component| {
@@ -93,7 +185,8 @@ impl Context {
                     let s = s.clone();
                     let tx = tx.clone();
                     move |view| {
-                        *view = component.call(&s);
+                        *view = s.render(&component);
+                        s.request_redraw();
                         let _ = tx.send(()); // signal completion
                     }
                 });
@@ -104,6 +197,94 @@ impl Context {
         let _ = rx.recv();
     }
 
+    /// Replaces the component rendered by this context
+    ///
+    /// Takes effect on the next refresh. The hooks are kept, so the new
+    /// component should be of the same type as the previous one.
+    pub(crate) fn set_component<F: ComponentImpl + 'static>(&self, component: F) {
+        self.component
+            .access(move |current| *current = Arc::new(component));
+    }
+
+    /// Checks whether this context renders a component of type F
+    pub(crate) fn renders<F: ComponentImpl + 'static>(&self) -> bool {
+        self.kind == TypeId::of::<F>()
+    }
+
+    /// Resets the state set up by the previous render
+    ///
+    /// The scopes of the previous render are set aside, to be reused by
+    /// the scopes the component creates.
+    fn prepare_render(self: &Arc<Self>) {
+        let scopes = std::mem::take(&mut *self.scopes.lock().unwrap());
+        self.previous_scopes.lock().unwrap().extend(scopes);
+        self.event_handlers
+            .access(|event_handlers| event_handlers.clear());
+        self.capture_handlers
+            .access(|capture_handlers| capture_handlers.clear());
+        *self.focusable.lock().unwrap() = false;
+    }
+
+    /// Calls the component with its hooks stored on this context
+    fn render(self: &Arc<Self>, component: &Component) -> View {
+        self.prepare_render();
+        self.hooks.lock().unwrap().start();
+        let view = rendering(Some(self.clone()), || component.call(self));
+        let finished = self.hooks.lock().unwrap().finish();
+        self.finish_render();
+
+        // Panicking with the hooks unlocked keeps them usable
+        if let Err(message) = finished {
+            panic!("{message}");
+        }
+
+        view
+    }
+
+    /// Unmounts the children the render did not reuse
+    fn finish_render(self: &Arc<Self>) {
+        for scope in std::mem::take(&mut *self.previous_scopes.lock().unwrap()) {
+            scope.unmount();
+        }
+
+        let scopes = self.scopes.lock().unwrap().clone();
+        for scope in scopes {
+            scope.end();
+        }
+    }
+
+    /// Registers a function to run when the component unmounts
+    ///
+    /// Unlike event handlers, unmount handlers are kept across renders;
+    /// prefer the `use_unmount` hook in a component function.
+    pub fn on_unmount<F: FnOnce() + Send + 'static>(self: &Arc<Self>, f: F) {
+        self.unmount_handlers.lock().unwrap().push(Box::new(f));
+    }
+
+    /// Unmounts the component and its descendants
+    ///
+    /// Children unmount first. Then the unmount handlers run, the effects
+    /// of the component stop and its hooks are dropped. Scopes unmount the
+    /// children they remove, and dropping a context unmounts it as well.
+    pub fn unmount(&self) {
+        let previous = std::mem::take(&mut *self.previous_scopes.lock().unwrap());
+        let scopes = std::mem::take(&mut *self.scopes.lock().unwrap());
+        for scope in previous.into_iter().chain(scopes) {
+            scope.unmount();
+        }
+
+        let handlers = std::mem::take(&mut *self.unmount_handlers.lock().unwrap());
+        for handler in handlers.into_iter().rev() {
+            handler();
+        }
+
+        self.hooks.lock().unwrap().clear();
+        self.event_handlers
+            .access(|event_handlers| event_handlers.clear());
+        self.capture_handlers
+            .access(|capture_handlers| capture_handlers.clear());
+    }
+
     /// Gets the current view
     pub fn get_view(self: &Arc<Self>) -> View {
         self.view.access_ref().clone()
@@ -112,46 +293,185 @@ impl Context {
     /// Registers an event handler for events of type T
     ///
     /// When an event of type T is emitted, the handler is called with
-    /// the context and a reference to the event.
+    /// the context and a reference to the event. The handler runs in the
+    /// target and bubble phases, inside a `batch`.
     pub fn on_event<T: Any + 'static, F: Fn(&Arc<Self>, &T) + Send + Sync + 'static>(
         self: &Arc<Self>,
         handler: F,
     ) {
-        let new_handler: EventHandler =
-            Arc::new(Mutex::new(move |ctx: &Arc<Context>, event: &dyn Any| {
-                if let Some(e) = event.downcast_ref::<T>() {
-                    (handler)(ctx, e);
-                }
-            }));
-        self.event_handlers.access(|event_handlers| {
-            event_handlers
-                .entry(TypeId::of::<T>())
-                .or_insert_with(Vec::new)
-                .push(new_handler);
-        });
+        self.on_event_ctx(move |cx, event: &T, _| handler(cx, event));
+    }
+
+    /// Registers an event handler that receives the dispatch state
+    ///
+    /// Like `on_event`, but the handler can inspect the phase and stop
+    /// propagation through the `EventCtx`.
+    pub fn on_event_ctx<
+        T: Any + 'static,
+        F: Fn(&Arc<Self>, &T, &EventCtx) + Send + Sync + 'static,
+    >(
+        self: &Arc<Self>,
+        handler: F,
+    ) {
+        add_handler(&self.event_handlers, handler);
+    }
+
+    /// Registers an event handler for the capture phase
+    ///
+    /// Capture handlers run on the ancestors of the target, from the root
+    /// down, before the target itself sees the event. This lets a parent
+    /// intercept events meant for its descendants.
+    pub fn on_event_capture<
+        T: Any + 'static,
+        F: Fn(&Arc<Self>, &T, &EventCtx) + Send + Sync + 'static,
+    >(
+        self: &Arc<Self>,
+        handler: F,
+    ) {
+        add_handler(&self.capture_handlers, handler);
     }
 
     /// Emits an event to this component and all descendants
     ///
-    /// Calls all registered handlers for this event type,
-    /// then propagates the event to child components.
+    /// Calls all registered handlers for this event type, capture handlers
+    /// first, then propagates the event to child components. A handler that
+    /// stops propagation keeps the event from reaching that component's
+    /// descendants.
     pub fn emit_event<E: Send + Sync + Any + 'static>(self: &Arc<Self>, event: E) {
-        let event = Arc::new(event);
+        self.emit_event_dyn(TypeId::of::<E>(), &event);
+    }
+
+    /// Dispatches a type-erased event to this component and all descendants
+    ///
+    /// Returns whether a handler in the subtree stopped the event.
+    fn emit_event_dyn(self: &Arc<Self>, type_id: TypeId, event: &dyn Any) -> bool {
+        let ecx = EventCtx::new(self.clone(), EventPhase::Target);
+
+        self.call_handlers(&self.capture_handlers, type_id, event, &ecx);
+        self.call_handlers(&self.event_handlers, type_id, event, &ecx);
+
+        if ecx.is_propagation_stopped() {
+            return true;
+        }
+
+        let mut stopped = false;
+        for child in self.get_children() {
+            stopped |= child.0.emit_event_dyn(type_id, event);
+        }
+        stopped
+    }
+
+    /// Calls this component's handlers for a type-erased event
+    fn call_handlers(
+        self: &Arc<Self>,
+        handlers: &AccessCell<HashMap<TypeId, Vec<EventHandler>>>,
+        type_id: TypeId,
+        event: &dyn Any,
+        ecx: &EventCtx,
+    ) {
         let handlers_to_call: Vec<EventHandler> = {
-            let guard = self.event_handlers.access_ref();
-            guard.get(&TypeId::of::<E>()).cloned().unwrap_or_default()
+            let guard = handlers.access_ref();
+            guard.get(&type_id).cloned().unwrap_or_default()
         };
-        for h in &handlers_to_call {
-            (h.lock().unwrap())(self, event.as_ref());
+        batch(|| {
+            for h in &handlers_to_call {
+                (h.lock().unwrap())(self, event, ecx);
+            }
+        });
+    }
+
+    /// Emits an event to this component only
+    ///
+    /// Unlike `emit_event`, the event is not propagated to children.
+    pub fn emit_event_local<E: Send + Sync + Any + 'static>(self: &Arc<Self>, event: E) {
+        let ecx = EventCtx::new(self.clone(), EventPhase::Target);
+        self.call_handlers(&self.event_handlers, TypeId::of::<E>(), &event, &ecx);
+    }
+
+    /// Dispatches an event with this component as the target
+    ///
+    /// The event goes through three phases, any of which can stop it:
+    /// - Capture: capture handlers of each ancestor, from the root down
+    /// - Target: capture handlers, then regular handlers of this component
+    /// - Bubble: regular handlers of each ancestor, from the parent up
+    ///
+    /// Returns whether a handler stopped the event.
+    pub fn dispatch_event<E: Send + Sync + Any + 'static>(self: &Arc<Self>, event: E) -> bool {
+        let type_id = TypeId::of::<E>();
+        let ancestors = self.get_ancestors();
+        let ecx = EventCtx::new(self.clone(), EventPhase::Capture);
+
+        for cx in ancestors.iter().rev() {
+            cx.call_handlers(&cx.capture_handlers, type_id, &event, &ecx);
+            if ecx.is_propagation_stopped() {
+                return true;
+            }
         }
 
-        for scope in self.scopes.lock().unwrap().iter() {
-            for (child, _) in scope.children.lock().unwrap().iter() {
-                child.emit_event(event.clone());
+        ecx.set_phase(EventPhase::Target);
+        self.call_handlers(&self.capture_handlers, type_id, &event, &ecx);
+        self.call_handlers(&self.event_handlers, type_id, &event, &ecx);
+        if ecx.is_propagation_stopped() {
+            return true;
+        }
+
+        ecx.set_phase(EventPhase::Bubble);
+        self.bubble(&ancestors, type_id, &event, &ecx);
+        ecx.is_propagation_stopped()
+    }
+
+    /// Emits an event to the ancestors of this component
+    ///
+    /// The event bubbles from the parent up to the root and can be stopped
+    /// along the way. Children use this to notify their owners, e.g. that
+    /// a form was submitted.
+    pub fn emit_up<E: Send + Sync + Any + 'static>(self: &Arc<Self>, event: E) {
+        let ecx = EventCtx::new(self.clone(), EventPhase::Bubble);
+        self.bubble(&self.get_ancestors(), TypeId::of::<E>(), &event, &ecx);
+    }
+
+    fn bubble(
+        self: &Arc<Self>,
+        ancestors: &[Arc<Context>],
+        type_id: TypeId,
+        event: &dyn Any,
+        ecx: &EventCtx,
+    ) {
+        for cx in ancestors {
+            cx.call_handlers(&cx.event_handlers, type_id, event, ecx);
+            if ecx.is_propagation_stopped() {
+                return;
             }
         }
     }
 
+    /// Emits a key event, taking focus into account
+    ///
+    /// - Tab and Shift-Tab presses move focus through the tree
+    /// - If a component is focused, the key is dispatched with it as the
+    ///   target (see `dispatch_event`)
+    /// - Otherwise, the key is emitted to this component and all descendants
+    ///
+    /// Returns whether the key was handled, either by moving focus or by a
+    /// handler stopping it.
+    pub fn emit_key_event(self: &Arc<Self>, key: KeyEvent) -> bool {
+        if matches!(key.code, KeyCode::Tab | KeyCode::BackTab) && !focus_order(self).is_empty() {
+            if key.kind == KeyEventKind::Press {
+                if key.code == KeyCode::Tab {
+                    self.focus.focus_next(self);
+                } else {
+                    self.focus.focus_prev(self);
+                }
+            }
+            return true;
+        }
+
+        match self.focus.focused() {
+            Some(focused) => focused.dispatch_event(key),
+            None => self.emit_event_dyn(TypeId::of::<KeyEvent>(), &key),
+        }
+    }
+
     /// Emits an event to this component in a spawned thread
     ///
     /// Similar to emit_event but handlers are called in spawned threads
@@ -168,7 +488,8 @@ impl Context {
             let event = event.clone();
             let s = self.clone();
             std::thread::spawn(move || {
-                (h.lock().unwrap())(&s, &event);
+                let ecx = EventCtx::new(s.clone(), EventPhase::Target);
+                batch(|| (h.lock().unwrap())(&s, &event, &ecx));
             });
         }
 
@@ -180,33 +501,52 @@ impl Context {
     }
 
     /// Creates a new child scope
+    ///
+    /// While the component re-renders, the scope created at the same
+    /// position by the previous render is returned instead, so that the
+    /// children added to it can reuse the previous ones.
     pub fn scope(self: &Arc<Self>) -> Arc<Scope> {
-        let scope = Scope::new(self.executor.clone());
+        let previous = self.previous_scopes.lock().unwrap().pop_front();
+        let scope = match previous {
+            Some(scope) => {
+                scope.stop_effects();
+                scope.begin();
+                scope
+            }
+            None => Scope::with_owner(self),
+        };
         self.scopes.lock().unwrap().push(scope.clone());
 
         scope
     }
 
     /// Creates a dynamic child scope that re-renders when dependencies change
+    ///
+    /// Like `scope`, a re-render of the component reuses the scope of the
+    /// previous render. The drawer stops running when the component
+    /// re-renders, registering the new one, or when the scope unmounts.
     pub fn dyn_scope<F: Fn(&Arc<Scope>) + Send + Sync + 'static>(
         self: &Arc<Self>,
         drawer: F,
         dependencies: &[&dyn HookDependency],
     ) -> Arc<Scope> {
-        let scope = Scope::new(self.executor.clone());
-        self.scopes.lock().unwrap().push(scope.clone());
+        let scope = self.scope();
 
-        drawer(&scope);
+        rendering(None, || drawer(&scope));
 
-        use_effect(
+        let hook = effect(
             {
                 let scope = scope.clone();
                 move || {
+                    scope.begin();
                     drawer(&scope);
+                    scope.end();
                 }
             },
             dependencies,
+            self.get_scheduler(),
         );
+        scope.add_effect(hook);
 
         scope
     }
@@ -241,11 +581,101 @@ impl Context {
         }
     }
 
+    /// Gets the parent context, if this is not the root
+    pub fn get_parent(self: &Arc<Self>) -> Option<Arc<Context>> {
+        self.parent.as_ref().and_then(Weak::upgrade)
+    }
+
+    /// Gets the ancestors of this component, from the parent up to the root
+    pub fn get_ancestors(self: &Arc<Self>) -> Vec<Arc<Context>> {
+        let mut ancestors = Vec::new();
+        let mut current = self.get_parent();
+        while let Some(cx) = current {
+            current = cx.get_parent();
+            ancestors.push(cx);
+        }
+        ancestors
+    }
+
+    /// Provides a value to this component and its descendants
+    ///
+    /// Descendants get it with `consume` or `use_context`, looked up by
+    /// type; a value provided closer to them takes precedence. Providing
+    /// a value of the same type again replaces it. Call this before the
+    /// children are created, so they see the value on their first render.
+    pub fn provide<T: Send + Sync + 'static>(self: &Arc<Self>, value: T) {
+        self.provided
+            .lock()
+            .unwrap()
+            .insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Gets the value of type T provided by this component or the
+    /// nearest ancestor providing one
+    pub fn consume<T: Clone + 'static>(self: &Arc<Self>) -> Option<T> {
+        std::iter::once(self.clone())
+            .chain(self.get_ancestors())
+            .find_map(|cx| {
+                let provided = cx.provided.lock().unwrap();
+                provided
+                    .get(&TypeId::of::<T>())
+                    .and_then(|value| value.downcast_ref::<T>())
+                    .cloned()
+            })
+    }
+
+    /// Gets the focus manager shared by this component tree
+    pub fn get_focus(self: &Arc<Self>) -> Arc<FocusManager> {
+        self.focus.clone()
+    }
+
+    /// Marks this component as able to receive focus
+    ///
+    /// Focusable components are cycled through with Tab and Shift-Tab.
+    /// Must be called on every render, like `on_event`.
+    pub fn focusable(self: &Arc<Self>) {
+        *self.focusable.lock().unwrap() = true;
+    }
+
+    /// Checks whether this component can receive focus
+    pub fn is_focusable(self: &Arc<Self>) -> bool {
+        *self.focusable.lock().unwrap()
+    }
+
+    /// Checks whether this component has focus
+    pub fn is_focused(self: &Arc<Self>) -> bool {
+        self.focus
+            .focused()
+            .is_some_and(|focused| Arc::ptr_eq(&focused, self))
+    }
+
+    /// Moves focus to this component
+    pub fn request_focus(self: &Arc<Self>) {
+        self.focus.focus(self);
+    }
+
     /// Gets the command executor for this context
     pub fn get_executor(self: &Arc<Self>) -> Arc<dyn CommandExecutor> {
         self.executor.clone()
     }
 
+    /// Gets the scheduler running the effects of this component tree
+    ///
+    /// Returns `None` for trees not run by an engine.
+    pub fn get_scheduler(self: &Arc<Self>) -> Option<Arc<EffectScheduler>> {
+        self.scheduler.clone()
+    }
+
+    /// Requests that the engine running this tree renders a new frame
+    ///
+    /// Trees not run by an engine wake every engine, like `request_redraw`.
+    pub fn request_redraw(&self) {
+        match &self.scheduler {
+            Some(scheduler) => scheduler.request_redraw(),
+            None => request_redraw(),
+        }
+    }
+
     /// Executes a command
     pub fn execute<T: Command + 'static>(self: &Arc<Self>, command: T) -> crate::Result<()> {
         self.executor
@@ -257,3 +687,29 @@ impl Context {
         self.execute(crate::engine::commands::Stop)
     }
 }
+
+/// Wraps a typed handler and adds it to a handler map
+fn add_handler<T: Any + 'static, F: Fn(&Arc<Context>, &T, &EventCtx) + Send + Sync + 'static>(
+    handlers: &AccessCell<HashMap<TypeId, Vec<EventHandler>>>,
+    handler: F,
+) {
+    let new_handler: EventHandler = Arc::new(Mutex::new(
+        move |ctx: &Arc<Context>, event: &dyn Any, ecx: &EventCtx| {
+            if let Some(e) = event.downcast_ref::<T>() {
+                (handler)(ctx, e, ecx);
+            }
+        },
+    ));
+    handlers.access(|handlers| {
+        handlers
+            .entry(TypeId::of::<T>())
+            .or_insert_with(Vec::new)
+            .push(new_handler);
+    });
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.unmount();
+    }
+}
@@ -0,0 +1,124 @@
+//! # Focus Module
+//!
+//! Provides the FocusManager which tracks the component receiving keyboard input.
+//! Components opt in with `Context::focusable` (or the `use_focus` hook) and are
+//! cycled through in tree order with Tab and Shift-Tab.
+
+use std::sync::{Arc, Mutex, Weak};
+
+use super::context::Context;
+
+/// Emitted to a component when it receives focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusGained;
+
+/// Emitted to a component when it loses focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusLost;
+
+/// Tracks which component of a tree currently has focus
+///
+/// A single manager is shared by every context of a component tree.
+pub struct FocusManager {
+    /// The focused component, if any
+    focused: Mutex<Option<Weak<Context>>>,
+}
+
+impl FocusManager {
+    /// Creates a new focus manager with nothing focused
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            focused: Mutex::new(None),
+        })
+    }
+
+    /// Gets the focused component, if it is still alive
+    pub fn focused(&self) -> Option<Arc<Context>> {
+        self.focused
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(Weak::upgrade)
+    }
+
+    /// Moves focus to the given component
+    ///
+    /// Emits `FocusLost` to the previously focused component and
+    /// `FocusGained` to the new one.
+    pub fn focus(&self, cx: &Arc<Context>) {
+        let previous = self.focused();
+
+        if previous.as_ref().is_some_and(|p| Arc::ptr_eq(p, cx)) {
+            return;
+        }
+
+        *self.focused.lock().unwrap() = Some(Arc::downgrade(cx));
+
+        if let Some(previous) = previous {
+            previous.emit_event_local(FocusLost);
+        }
+        cx.emit_event_local(FocusGained);
+        cx.request_redraw();
+    }
+
+    /// Removes focus from the focused component
+    pub fn blur(&self) {
+        let previous = self.focused.lock().unwrap().take();
+
+        if let Some(previous) = previous.as_ref().and_then(Weak::upgrade) {
+            previous.emit_event_local(FocusLost);
+            previous.request_redraw();
+        }
+    }
+
+    /// Focuses the next focusable component after the focused one
+    ///
+    /// Wraps around to the first focusable component under `root`.
+    pub fn focus_next(&self, root: &Arc<Context>) {
+        self.cycle(root, 1);
+    }
+
+    /// Focuses the previous focusable component before the focused one
+    ///
+    /// Wraps around to the last focusable component under `root`.
+    pub fn focus_prev(&self, root: &Arc<Context>) {
+        self.cycle(root, -1);
+    }
+
+    fn cycle(&self, root: &Arc<Context>, step: isize) {
+        let order = focus_order(root);
+
+        if order.is_empty() {
+            return;
+        }
+
+        let len = order.len() as isize;
+        let next = match self
+            .focused()
+            .and_then(|f| order.iter().position(|c| Arc::ptr_eq(c, &f)))
+        {
+            Some(i) => (i as isize + step).rem_euclid(len),
+            None if step > 0 => 0,
+            None => len - 1,
+        };
+
+        self.focus(&order[next as usize]);
+    }
+}
+
+/// Collects the focusable components under `root` in tree order
+pub fn focus_order(root: &Arc<Context>) -> Vec<Arc<Context>> {
+    let mut order = Vec::new();
+    collect_focusable(root, &mut order);
+    order
+}
+
+fn collect_focusable(cx: &Arc<Context>, order: &mut Vec<Arc<Context>>) {
+    if cx.is_focusable() {
+        order.push(cx.clone());
+    }
+
+    for (child, _) in cx.get_children() {
+        collect_focusable(&child, order);
+    }
+}
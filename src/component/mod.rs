@@ -6,11 +6,15 @@
 
 pub mod components;
 pub mod context;
+pub mod focus;
 pub mod scope;
 
 use std::{
     any::Any,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
 };
 
 use crate::View;
@@ -21,7 +25,31 @@ use context::Context;
 pub type Component = Arc<dyn ComponentImpl>;
 
 /// An event handler function stored in a mutex for thread-safe mutation
-pub type EventHandler = Arc<Mutex<dyn FnMut(&Arc<Context>, &dyn Any) + Send + Sync>>;
+pub type EventHandler = Arc<Mutex<dyn FnMut(&Arc<Context>, &dyn Any, &EventCtx) + Send + Sync>>;
+
+/// The phase an event is in while being dispatched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// Travelling from the root down to the target
+    Capture,
+    /// Being handled by the target itself
+    Target,
+    /// Travelling from the target's parent up to the root
+    Bubble,
+}
+
+/// Dispatch state passed to event handlers
+///
+/// Lets a handler find out where an event is headed and stop it from
+/// propagating any further.
+pub struct EventCtx {
+    /// The component the event was dispatched to
+    target: Arc<Context>,
+    /// The current dispatch phase
+    phase: Mutex<EventPhase>,
+    /// Whether a handler stopped propagation
+    stopped: AtomicBool,
+}
 
 /// Trait implemented by components to render themselves
 pub trait ComponentImpl: Send + Sync {
@@ -43,3 +71,40 @@ where
         self(ctx)
     }
 }
+
+impl EventCtx {
+    /// Creates a new dispatch state for the given target
+    pub fn new(target: Arc<Context>, phase: EventPhase) -> Self {
+        Self {
+            target,
+            phase: Mutex::new(phase),
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    /// Gets the component the event was dispatched to
+    pub fn target(&self) -> &Arc<Context> {
+        &self.target
+    }
+
+    /// Gets the current dispatch phase
+    pub fn phase(&self) -> EventPhase {
+        *self.phase.lock().unwrap()
+    }
+
+    pub(crate) fn set_phase(&self, phase: EventPhase) {
+        *self.phase.lock().unwrap() = phase;
+    }
+
+    /// Stops the event from reaching any further components
+    ///
+    /// Remaining handlers on the current component still run.
+    pub fn stop_propagation(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+
+    /// Checks whether a handler stopped propagation
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.stopped.load(Ordering::SeqCst)
+    }
+}
@@ -3,12 +3,43 @@
 //! Provides the Scope type for managing component hierarchies.
 //! Scopes group child components and manage their lifecycle.
 
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::{
+        hash_map::{DefaultHasher, Entry},
+        HashMap, VecDeque,
+    },
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex, Weak},
+};
 
-use crate::{engine::CommandExecutor, View, ViewWrapper};
+use crate::{engine::CommandExecutor, hooks::HookEffect, View, ViewWrapper};
 
 use super::{context::Context, ComponentImpl};
 
+/// Child component with its optional view wrapper
+type Child = (Arc<Context>, Option<ViewWrapper>);
+
+/// Children of a keyed scope left over from its previous render
+///
+/// Returned by `Scope::take_keyed`. Children whose key is not rendered
+/// again are unmounted when this value is dropped.
+pub struct KeyedChildren(HashMap<u64, Vec<Child>>);
+
+impl Drop for KeyedChildren {
+    fn drop(&mut self) {
+        for (child, _) in self.0.drain().flat_map(|(_, children)| children) {
+            child.unmount();
+        }
+    }
+}
+
+/// Hashes a key of a keyed scope
+pub fn key_of<K: Hash + ?Sized>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// A scope groups child components and manages their rendering
 ///
 /// Scopes form the hierarchical structure of a component tree.
@@ -17,28 +48,88 @@ use super::{context::Context, ComponentImpl};
 pub struct Scope {
     /// Child components with optional view wrappers
     pub children: Mutex<Vec<(Arc<Context>, Option<ViewWrapper>)>>,
+    /// Children of the previous render, reused in order by the next ones
+    previous: Mutex<VecDeque<Child>>,
+    /// Key and number of children of every keyed item, in order
+    keys: Mutex<Vec<(u64, usize)>>,
+    /// Index of the branch whose children this scope holds
+    branch: Mutex<Option<usize>>,
+    /// Effects re-rendering this scope, stopped when it unmounts
+    effects: Mutex<Vec<HookEffect>>,
     /// Command executor for this scope's children
     executor: Arc<dyn CommandExecutor>,
+    /// The context this scope belongs to
+    owner: Weak<Context>,
 }
 
 impl Scope {
     /// Creates a new scope with the given command executor
+    ///
+    /// The scope has no owner, so its children are root contexts without
+    /// a parent or an effect scheduler.
+    #[deprecated(note = "use `Scope::with_owner` or `Context::scope` instead")]
     pub fn new(executor: Arc<dyn CommandExecutor>) -> Arc<Self> {
+        Self::create(executor, Weak::new())
+    }
+
+    /// Creates a new scope owned by the given context
+    ///
+    /// Children created in this scope use the owner's command executor
+    /// and have the owner as their parent.
+    pub fn with_owner(owner: &Arc<Context>) -> Arc<Self> {
+        Self::create(owner.get_executor(), Arc::downgrade(owner))
+    }
+
+    fn create(executor: Arc<dyn CommandExecutor>, owner: Weak<Context>) -> Arc<Self> {
         Arc::new(Self {
             children: Mutex::new(Vec::new()),
+            previous: Mutex::new(VecDeque::new()),
+            keys: Mutex::new(Vec::new()),
+            branch: Mutex::new(None),
+            effects: Mutex::new(Vec::new()),
             executor,
+            owner,
         })
     }
 
+    /// Creates a context for a child component of this scope
+    fn create_context<F: ComponentImpl + 'static>(self: &Arc<Self>, child: F) -> Arc<Context> {
+        match self.owner.upgrade() {
+            Some(owner) => Context::new_child(child, &owner),
+            None => Context::new(child, self.executor.clone()),
+        }
+    }
+
+    /// Gets the context of the next child, reusing the previous one at
+    /// this position if it renders the same type of component
+    ///
+    /// A reused context gets the new component and keeps its hooks.
+    fn reuse_context<F: ComponentImpl + 'static>(self: &Arc<Self>, child: F) -> Arc<Context> {
+        let previous = self.previous.lock().unwrap().pop_front();
+        match previous {
+            Some((ctx, _)) if ctx.renders::<F>() => {
+                ctx.set_component(child);
+                ctx
+            }
+            Some((ctx, _)) => {
+                ctx.unmount();
+                self.create_context(child)
+            }
+            None => self.create_context(child),
+        }
+    }
+
     /// Adds a child component to this scope
     ///
     /// The view_wrapper is optional and can be used for layout or styling.
+    /// When this scope re-renders, the child at the same position is kept
+    /// if it has the same type, so its state survives.
     pub fn child<F: ComponentImpl + 'static>(
         self: &Arc<Self>,
         child: F,
         view_wrapper: Option<ViewWrapper>,
     ) {
-        let ctx = Context::new(child, self.executor.clone());
+        let ctx = self.reuse_context(child);
 
         ctx.refresh();
 
@@ -47,7 +138,7 @@ impl Scope {
 
     /// Adds a view directly to this scope
     pub fn view(self: &Arc<Self>, view: View) {
-        let ctx = Context::new(view, self.executor.clone());
+        let ctx = self.reuse_context(view);
 
         ctx.refresh();
 
@@ -56,10 +147,118 @@ impl Scope {
 
     /// Adds a view directly to this scope
     pub fn view_wrapped(self: &Arc<Self>, view: View, view_wrapper: ViewWrapper) {
-        let ctx = Context::new(view, self.executor.clone());
+        let ctx = self.reuse_context(view);
 
         ctx.refresh();
 
-        self.children.lock().unwrap().push((ctx, Some(view_wrapper)));
+        self.children
+            .lock()
+            .unwrap()
+            .push((ctx, Some(view_wrapper)));
+    }
+
+    /// Unmounts and removes every child of this scope
+    pub fn clear(self: &Arc<Self>) {
+        self.begin();
+        self.end();
+    }
+
+    /// Starts a new render of this scope
+    ///
+    /// The current children are set aside, to be reused by the children
+    /// added next.
+    pub(crate) fn begin(&self) {
+        let children = std::mem::take(&mut *self.children.lock().unwrap());
+        self.previous.lock().unwrap().extend(children);
+    }
+
+    /// Finishes a render of this scope, unmounting the children not reused
+    pub(crate) fn end(&self) {
+        let previous = std::mem::take(&mut *self.previous.lock().unwrap());
+        for (child, _) in previous {
+            child.unmount();
+        }
+    }
+
+    /// Stops the effects re-rendering this scope
+    pub(crate) fn stop_effects(&self) {
+        for effect in self.effects.lock().unwrap().drain(..) {
+            effect.cancel();
+        }
+    }
+
+    /// Stops the effects of this scope and unmounts its children
+    pub(crate) fn unmount(&self) {
+        self.stop_effects();
+        self.begin();
+        self.end();
+    }
+
+    /// Ties an effect to this scope, stopping it when the scope unmounts
+    pub(crate) fn add_effect(&self, effect: HookEffect) {
+        self.effects.lock().unwrap().push(effect);
+    }
+
+    /// Takes the children of this scope, grouped by their key
+    ///
+    /// Used with `keyed` to re-render a list while keeping the children
+    /// of items that are still present. Children added without a key
+    /// are unmounted.
+    pub fn take_keyed(self: &Arc<Self>) -> KeyedChildren {
+        self.begin();
+        let mut children = std::mem::take(&mut *self.previous.lock().unwrap()).into_iter();
+        let keys = std::mem::take(&mut *self.keys.lock().unwrap());
+
+        let mut keyed = KeyedChildren(HashMap::new());
+        for (key, count) in keys {
+            let group: Vec<Child> = children.by_ref().take(count).collect();
+            // Only the first item of a duplicated key is kept
+            match keyed.0.entry(key) {
+                Entry::Occupied(_) => group.into_iter().for_each(|(child, _)| child.unmount()),
+                Entry::Vacant(entry) => {
+                    entry.insert(group);
+                }
+            }
+        }
+        children.for_each(|(child, _)| child.unmount());
+
+        keyed
+    }
+
+    /// Adds the children of a keyed item
+    ///
+    /// `create` adds the children of the item. When `previous` holds
+    /// children with the same key, they are reused by the new ones in
+    /// order, so they keep their state while getting the new item.
+    pub fn keyed<F: FnOnce(&Arc<Self>)>(
+        self: &Arc<Self>,
+        previous: &mut KeyedChildren,
+        key: u64,
+        create: F,
+    ) {
+        let kept = previous.0.remove(&key).unwrap_or_default();
+        *self.previous.lock().unwrap() = kept.into();
+
+        let before = self.children.lock().unwrap().len();
+        create(self);
+        self.end();
+        let count = self.children.lock().unwrap().len() - before;
+
+        self.keys.lock().unwrap().push((key, count));
+    }
+
+    /// Renders the branch taken by a conditional
+    ///
+    /// When `index` is the branch rendered last, `create` reuses its
+    /// children, which keep their state while getting the new bindings.
+    /// Otherwise they are unmounted first.
+    pub fn branch<F: FnOnce(&Arc<Self>)>(self: &Arc<Self>, index: usize, create: F) {
+        self.begin();
+        if self.branch.lock().unwrap().replace(index) != Some(index) {
+            self.end();
+        }
+
+        create(self);
+        self.end();
     }
 }
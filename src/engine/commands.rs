@@ -12,3 +12,12 @@ impl Command for Stop {
         self
     }
 }
+
+/// Command to request that the engine renders a new frame
+pub struct Redraw;
+
+impl Command for Redraw {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -4,16 +4,32 @@
 //! to the terminal using crossterm.
 
 use std::{
+    collections::VecDeque,
     io::{stdout, Write},
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
-use crossterm::{cursor::MoveTo, execute, terminal::Clear};
+use crossterm::{
+    cursor::MoveTo,
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+        KeyModifiers,
+    },
+    execute, queue,
+    style::{Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{disable_raw_mode, enable_raw_mode, Clear},
+};
 
 use crate::component::{context::Context, ComponentImpl};
 use crate::{
-    engine::{commands, CommandExecutor},
-    render::Area,
+    buffer::Buffer,
+    engine::{
+        commands, events::emit_terminal_event, CommandExecutor, EffectScheduler, RedrawSignal,
+    },
+    render::{Area, Style},
+    text::grapheme_width,
     DrawContext, View,
 };
 
@@ -23,33 +39,140 @@ use super::Engine;
 pub struct ConsoleExecutor {
     /// Flag indicating whether the application is running
     running: Mutex<bool>,
+    /// Wakes the render loop of the engine
+    redraw: Arc<RedrawSignal>,
 }
 
+/// Terminal input read by the input thread, waiting for the render loop
+type Input = Arc<Mutex<VecDeque<std::io::Result<Event>>>>;
+
 /// Console-based rendering engine
 ///
 /// Renders components to the terminal using crossterm for cross-platform support.
+/// While running, terminal input is read on a separate thread, which wakes the
+/// render loop; the loop then emits the input into the root context (see
+/// [`crate::engine::events`]) before rendering. Pressing `Ctrl+C` stops the
+/// engine, unless a component handles the key by stopping its propagation.
+/// Failing to read input stops it as well, and `run` returns the error.
+/// Frames are only rendered when a redraw is requested.
 pub struct Console {
     /// Thread functions to execute
     threads: Mutex<Vec<Arc<dyn Fn(Arc<Context>) + Send + Sync>>>,
     /// The executor for this console
     executor: Arc<ConsoleExecutor>,
+    /// The frame currently being drawn
+    buffer: Mutex<Buffer>,
+    /// The last frame written to the terminal
+    previous: Mutex<Buffer>,
+    /// Upper bound on the number of frames rendered per second
+    max_fps: Mutex<Option<u32>>,
+    /// The scheduler running the effects of this engine's components
+    scheduler: Arc<EffectScheduler>,
 }
 
 impl Console {
     /// Creates a new console engine
     pub fn new() -> Self {
+        let scheduler = Arc::new(EffectScheduler::new());
+
         Self {
             threads: Mutex::new(Vec::new()),
             executor: Arc::new(ConsoleExecutor {
                 running: Mutex::new(true),
+                redraw: scheduler.redraw_signal().clone(),
             }),
+            buffer: Mutex::new(Buffer::new(0, 0)),
+            previous: Mutex::new(Buffer::new(0, 0)),
+            max_fps: Mutex::new(None),
+            scheduler,
         }
     }
 
+    /// Limits how many frames are rendered per second
+    ///
+    /// Without a limit, a frame is rendered as soon as a redraw is requested.
+    pub fn max_fps(&self, fps: u32) {
+        *self.max_fps.lock().unwrap() = Some(fps);
+    }
+
+    /// Runs effects on `count` worker threads instead of the render thread
+    ///
+    /// See [`EffectScheduler::set_workers`].
+    pub fn effect_workers(&self, count: usize) {
+        self.scheduler.set_workers(count);
+    }
+
     /// Registers a thread function to run alongside the engine
     pub fn thread<F: Fn(Arc<Context>) + Send + Sync + 'static>(&self, run: F) {
         self.threads.lock().unwrap().push(Arc::new(run));
     }
+
+    /// Writes the cells that changed since the last frame to the terminal
+    ///
+    /// All changes are queued and flushed at once. The drawn frame then
+    /// becomes the previous frame.
+    fn flush(&self) {
+        let mut buffer = self.buffer.lock().unwrap();
+        let mut previous = self.previous.lock().unwrap();
+        let mut stdout = stdout().lock();
+
+        if previous.width != buffer.width || previous.height != buffer.height {
+            queue!(stdout, Clear(crossterm::terminal::ClearType::All)).unwrap();
+            *previous = Buffer::new(buffer.width, buffer.height);
+        }
+
+        let mut cursor: Option<(u16, u16)> = None;
+        let mut style = Style::default();
+
+        for (x, y, cell) in previous.diff(&buffer) {
+            // Covered by the wide character before it
+            if cell.symbol.is_empty() {
+                continue;
+            }
+            if cursor != Some((x, y)) {
+                queue!(stdout, MoveTo(x, y)).unwrap();
+            }
+            if cell.style != style {
+                queue_style(&mut stdout, &cell.style);
+                style = cell.style;
+            }
+            queue!(stdout, Print(&cell.symbol)).unwrap();
+            cursor = Some((x + grapheme_width(&cell.symbol), y));
+        }
+
+        queue!(stdout, SetAttribute(Attribute::Reset)).unwrap();
+        stdout.flush().unwrap();
+
+        std::mem::swap(&mut *previous, &mut *buffer);
+    }
+
+    /// Emits the input read since the last frame into the root context
+    ///
+    /// Stops the engine on a `Ctrl+C` no component handled, and returns the
+    /// first read error.
+    fn handle_input(&self, cx: &Arc<Context>, input: &Input) -> std::io::Result<()> {
+        let events = std::mem::take(&mut *input.lock().unwrap());
+
+        for event in events {
+            let event = event?;
+            let interrupt = matches!(
+                event,
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char('c'),
+                    modifiers: KeyModifiers::CONTROL,
+                    kind: KeyEventKind::Press,
+                    ..
+                })
+            );
+
+            if !emit_terminal_event(cx, event) && interrupt {
+                let _ = self.executor.stop();
+                break;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Engine for Console {
@@ -60,29 +183,22 @@ impl Engine for Console {
     }
 
     fn draw_context(&self, ctx: &DrawContext) {
-        for inst in &ctx.drawing {
-            match inst {
-                crate::render::DrawInstruction::Text(point, text) => {
-                    let (x, y) = (ctx.area.x + point.x, ctx.area.y + point.y);
-                    for (i, line) in text.lines().enumerate() {
-                        execute!(stdout(), MoveTo(x, y + (i as u16))).unwrap();
-                        print!("{line}");
-                    }
-                    stdout().flush().unwrap();
-                }
-                crate::render::DrawInstruction::Child(_point, child) => self.draw_context(child),
-                crate::render::DrawInstruction::View(area, view) => {
-                    self.draw_context(&self.render_view(area, view))
-                }
-            }
-        }
+        self.buffer.lock().unwrap().draw_context(ctx);
     }
 
     fn render(&self, cx: &Arc<Context>) {
+        self.scheduler.run_pending();
+
         let (width, height) = crossterm::terminal::size().unwrap();
 
-        execute!(stdout(), Clear(crossterm::terminal::ClearType::Purge)).unwrap();
-        execute!(stdout(), Clear(crossterm::terminal::ClearType::All)).unwrap();
+        {
+            let mut buffer = self.buffer.lock().unwrap();
+            if buffer.width != width || buffer.height != height {
+                buffer.resize(width, height);
+            } else {
+                buffer.reset();
+            }
+        }
 
         self.draw_context(&self.render_view(
             &Area {
@@ -93,12 +209,21 @@ impl Engine for Console {
             },
             &cx.get_view(),
         ));
+
+        self.flush();
     }
 
     fn init<C: ComponentImpl + 'static>(&self, component: C) -> Arc<Context> {
         crossterm::execute!(std::io::stdout(), crossterm::cursor::Hide).unwrap();
 
-        let cx = Context::new(component, self.executor.clone());
+        // Force the first frame to be written in full
+        *self.previous.lock().unwrap() = Buffer::new(0, 0);
+
+        let cx = Context::with_scheduler(
+            component,
+            self.executor.clone(),
+            self.scheduler.clone(),
+        );
         cx.refresh();
 
         for thread in self.threads.lock().unwrap().iter() {
@@ -113,14 +238,32 @@ impl Engine for Console {
         cx
     }
 
+    fn render_delay(&self) {
+        if let Some(fps) = *self.max_fps.lock().unwrap() {
+            std::thread::sleep(Duration::from_secs(1) / fps.max(1));
+        }
+
+        self.scheduler.redraw_signal().wait(None);
+    }
+
     fn executor(&self) -> Arc<dyn super::CommandExecutor> {
         self.executor.clone()
     }
 
     fn run<F: ComponentImpl + 'static>(&self, component: F) -> crate::Result<()> {
         let cx = self.init(component);
+        let _terminal = RawTerminal::enter();
+        let input = read_input(self.scheduler.redraw_signal().clone());
 
         while self.executor.is_running() {
+            if let Err(error) = self.handle_input(&cx, &input) {
+                self.executor.stop()?;
+                return Err(error.into());
+            }
+            if !self.executor.is_running() {
+                break;
+            }
+
             self.render(&cx);
             self.render_delay();
         }
@@ -129,6 +272,87 @@ impl Engine for Console {
     }
 }
 
+/// Starts the thread reading terminal input
+///
+/// The thread blocks until input arrives, queues it and wakes the render
+/// loop through `redraw`. It stops after a read error; otherwise it is
+/// left blocked on the next read when the engine stops.
+fn read_input(redraw: Arc<RedrawSignal>) -> Input {
+    let input = Input::default();
+
+    std::thread::spawn({
+        let input = input.clone();
+        move || loop {
+            let event = crossterm::event::read();
+            let failed = event.is_err();
+            input.lock().unwrap().push_back(event);
+            redraw.request();
+
+            if failed {
+                break;
+            }
+        }
+    });
+
+    input
+}
+
+/// Queues the commands switching the terminal to the given style
+fn queue_style(out: &mut impl Write, style: &Style) {
+    queue!(out, SetAttribute(Attribute::Reset)).unwrap();
+
+    if let Some(fg) = style.fg {
+        queue!(out, SetForegroundColor(fg)).unwrap();
+    }
+    if let Some(bg) = style.bg {
+        queue!(out, SetBackgroundColor(bg)).unwrap();
+    }
+    if style.bold {
+        queue!(out, SetAttribute(Attribute::Bold)).unwrap();
+    }
+    if style.italic {
+        queue!(out, SetAttribute(Attribute::Italic)).unwrap();
+    }
+    if style.underline {
+        queue!(out, SetAttribute(Attribute::Underlined)).unwrap();
+    }
+    if style.reverse {
+        queue!(out, SetAttribute(Attribute::Reverse)).unwrap();
+    }
+}
+
+/// Puts the terminal into raw mode with mouse, focus and paste reporting
+///
+/// The terminal is restored when the guard is dropped, including on panic.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> Self {
+        enable_raw_mode().unwrap();
+        execute!(
+            stdout(),
+            EnableMouseCapture,
+            EnableFocusChange,
+            EnableBracketedPaste
+        )
+        .unwrap();
+        Self
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(
+            stdout(),
+            DisableBracketedPaste,
+            DisableFocusChange,
+            DisableMouseCapture,
+            crossterm::cursor::Show
+        );
+        let _ = disable_raw_mode();
+    }
+}
+
 impl ConsoleExecutor {
     /// Checks if the engine is still running
     pub fn is_running(self: &Arc<ConsoleExecutor>) -> bool {
@@ -138,6 +362,7 @@ impl ConsoleExecutor {
     /// Stops the engine
     pub fn stop(&self) -> crate::Result<()> {
         *self.running.lock()? = false;
+        self.redraw.request();
         Ok(())
     }
 }
@@ -150,6 +375,10 @@ impl CommandExecutor for ConsoleExecutor {
             self.stop()?;
         }
 
+        if let Some(commands::Redraw) = command.downcast_ref() {
+            self.redraw.request();
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,62 @@
+//! # Events Module
+//!
+//! Defines the terminal events emitted by the engine into the root context.
+//!
+//! Key and mouse input is emitted as crossterm's own `KeyEvent` and
+//! `MouseEvent`; the remaining terminal events are wrapped in the types below
+//! so they can be handled with `Context::on_event`.
+
+use std::sync::Arc;
+
+use crossterm::event::Event;
+
+use crate::component::context::Context;
+
+use super::batch;
+
+/// Emitted when the terminal is resized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resize {
+    /// New width in terminal columns
+    pub width: u16,
+    /// New height in terminal rows
+    pub height: u16,
+}
+
+/// Emitted when the terminal window gains focus
+///
+/// Not to be confused with `component::focus::FocusGained`, emitted when a
+/// component gains focus within the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalFocusGained;
+
+/// Emitted when the terminal window loses focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalFocusLost;
+
+/// Emitted when text is pasted into the terminal (bracketed paste)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paste(pub String);
+
+/// Emits a crossterm event into the given context
+///
+/// The event is unwrapped into its typed form (`KeyEvent`, `MouseEvent`,
+/// `Resize`, `TerminalFocusGained`, `TerminalFocusLost` or `Paste`) before
+/// being emitted. Every state change made while handling it is applied as
+/// one batch. Key events are routed by focus (see `Context::emit_key_event`).
+///
+/// Returns whether the event was handled, which is only reported for key
+/// events; the engine uses it to let components override `Ctrl+C`.
+pub fn emit_terminal_event(cx: &Arc<Context>, event: Event) -> bool {
+    batch(|| {
+        match event {
+            Event::Key(key) => return cx.emit_key_event(key),
+            Event::Mouse(mouse) => cx.emit_event(mouse),
+            Event::Resize(width, height) => cx.emit_event(Resize { width, height }),
+            Event::FocusGained => cx.emit_event(TerminalFocusGained),
+            Event::FocusLost => cx.emit_event(TerminalFocusLost),
+            Event::Paste(text) => cx.emit_event(Paste(text)),
+        }
+        false
+    })
+}
@@ -0,0 +1,167 @@
+//! # Headless Engine Implementation
+//!
+//! Provides an Engine that renders into an in-memory buffer instead of the
+//! terminal, so components can be tested without a real console.
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::Event;
+
+use crate::component::{context::Context, ComponentImpl};
+use crate::{
+    buffer::{Buffer, Cell},
+    engine::{commands, events::emit_terminal_event, CommandExecutor, EffectScheduler},
+    render::Area,
+    DrawContext, View,
+};
+
+use super::Engine;
+
+/// Executes commands for the headless engine
+pub struct HeadlessExecutor {
+    /// Flag indicating whether the application is running
+    running: Mutex<bool>,
+}
+
+/// Headless rendering engine
+///
+/// Renders components into a fixed-size [`Buffer`]. Frames are only
+/// rendered when [`Headless::step`] is called, and events are injected
+/// with [`Headless::send`].
+///
+/// # Example
+///
+/// ```rust
+/// use osui::prelude::*;
+///
+/// let engine = Headless::new(12, 2);
+/// let cx = engine.init(Arc::new(|ctx: &mut DrawContext| {
+///     ctx.draw_text(Point { x: 1, y: 1 }, "Hello");
+/// }) as View);
+///
+/// engine.step(&cx);
+/// assert_eq!(engine.lines(), vec!["            ", " Hello      "]);
+/// ```
+pub struct Headless {
+    /// The last rendered frame
+    buffer: Mutex<Buffer>,
+    /// The executor for this engine
+    executor: Arc<HeadlessExecutor>,
+    /// The scheduler running the effects of this engine's components
+    scheduler: Arc<EffectScheduler>,
+}
+
+impl Headless {
+    /// Creates a new headless engine with a screen of the given size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Mutex::new(Buffer::new(width, height)),
+            executor: Arc::new(HeadlessExecutor {
+                running: Mutex::new(true),
+            }),
+            scheduler: Arc::new(EffectScheduler::new()),
+        }
+    }
+
+    /// Changes the screen size and emits a `Resize` event
+    pub fn resize(&self, cx: &Arc<Context>, width: u16, height: u16) {
+        self.buffer.lock().unwrap().resize(width, height);
+        self.send(cx, Event::Resize(width, height));
+    }
+
+    /// Injects a terminal event into the component tree
+    ///
+    /// Returns whether the event was handled (see `emit_terminal_event`).
+    pub fn send(&self, cx: &Arc<Context>, event: Event) -> bool {
+        emit_terminal_event(cx, event)
+    }
+
+    /// Runs the queued effects, then renders a single frame
+    pub fn step(&self, cx: &Arc<Context>) {
+        self.render(cx);
+    }
+
+    /// Returns a copy of the last rendered frame
+    pub fn buffer(&self) -> Buffer {
+        self.buffer.lock().unwrap().clone()
+    }
+
+    /// Returns the cell at the given position of the last rendered frame
+    pub fn cell(&self, x: u16, y: u16) -> Option<Cell> {
+        self.buffer.lock().unwrap().get(x, y).cloned()
+    }
+
+    /// Returns the last rendered frame as text, one string per row
+    pub fn lines(&self) -> Vec<String> {
+        self.buffer.lock().unwrap().to_lines()
+    }
+
+    /// Returns the last rendered frame as text, with rows joined by newlines
+    pub fn frame(&self) -> String {
+        self.lines().join("\n")
+    }
+
+    /// Checks whether a component has stopped the engine
+    pub fn is_running(&self) -> bool {
+        *self.executor.running.lock().unwrap()
+    }
+}
+
+impl Engine<Buffer> for Headless {
+    fn render_view(&self, area: &Area, view: &View) -> DrawContext {
+        let mut context = DrawContext::new(area.clone());
+        view(&mut context);
+        context
+    }
+
+    fn draw_context(&self, ctx: &DrawContext) {
+        self.buffer.lock().unwrap().draw_context(ctx);
+    }
+
+    fn render(&self, cx: &Arc<Context>) {
+        self.scheduler.run_pending();
+
+        let (width, height) = {
+            let mut buffer = self.buffer.lock().unwrap();
+            buffer.reset();
+            (buffer.width, buffer.height)
+        };
+
+        self.draw_context(&self.render_view(
+            &Area {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+            &cx.get_view(),
+        ));
+    }
+
+    fn init<C: ComponentImpl + 'static>(&self, component: C) -> Arc<Context> {
+        let cx = Context::with_scheduler(component, self.executor.clone(), self.scheduler.clone());
+        cx.refresh();
+        cx
+    }
+
+    fn executor(&self) -> Arc<dyn CommandExecutor> {
+        self.executor.clone()
+    }
+
+    /// Renders a single frame and returns it
+    fn run<C: ComponentImpl + 'static>(&self, component: C) -> crate::Result<Buffer> {
+        let cx = self.init(component);
+        self.step(&cx);
+        Ok(self.buffer())
+    }
+}
+
+impl CommandExecutor for HeadlessExecutor {
+    fn execute_command(&self, command: &Arc<dyn super::Command>) -> crate::Result<()> {
+        if let Some(commands::Stop) = command.as_any().downcast_ref() {
+            *self.running.lock()? = false;
+        }
+
+        Ok(())
+    }
+}
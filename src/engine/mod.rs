@@ -7,9 +7,16 @@
 pub mod benchmark;
 pub mod commands;
 pub mod console;
+pub mod events;
+pub mod headless;
+pub mod redraw;
+pub mod scheduler;
 
 pub use benchmark::*;
 pub use console::*;
+pub use headless::*;
+pub use redraw::*;
+pub use scheduler::*;
 
 use std::{any::Any, sync::Arc};
 
@@ -27,7 +34,11 @@ pub trait Engine<Output = ()> {
     /// Renders the current state of a component
     fn render(&self, cx: &Arc<Context>);
     
-    /// Sleeps between render frames (default 16ms for ~60fps)
+    /// Waits between render frames
+    ///
+    /// By default, sleeps for 16 milliseconds. Engines owning a
+    /// [`RedrawSignal`] block on it instead, so nothing is rendered while
+    /// the UI is idle.
     fn render_delay(&self) {
         crate::sleep(16);
     }
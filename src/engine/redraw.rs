@@ -0,0 +1,106 @@
+//! # Redraw Module
+//!
+//! Provides the signal used to wake an engine when a new frame is needed.
+//! Every engine has its own signal, which its component tree reaches through
+//! the Context: component refreshes, focus changes and effects request a
+//! redraw from their own engine with `Context::request_redraw`. State
+//! updates do not know which engines display them, so `request_redraw`
+//! wakes every engine instead. The engine sleeps until a redraw is
+//! requested instead of rendering continuously.
+
+use std::{
+    sync::{Arc, Condvar, Mutex, Weak},
+    time::Duration,
+};
+
+use super::scheduler::hold_redraw;
+
+/// A dirty flag paired with a condition variable to wake a waiting engine
+pub struct RedrawSignal {
+    /// Whether a redraw has been requested since the last wait
+    dirty: Mutex<bool>,
+    /// Notified whenever a redraw is requested
+    condvar: Condvar,
+}
+
+/// The signals woken by `request_redraw`, dropped with their engine
+static SHARED: Mutex<Vec<Weak<RedrawSignal>>> = Mutex::new(Vec::new());
+
+impl RedrawSignal {
+    /// Creates a new signal with no pending redraw
+    pub const fn new() -> Self {
+        Self {
+            dirty: Mutex::new(false),
+            condvar: Condvar::new(),
+        }
+    }
+
+    /// Creates a new signal that `request_redraw` wakes as well
+    ///
+    /// Used by engines, so that changes made outside of their component
+    /// tree still reach them.
+    pub fn shared() -> Arc<Self> {
+        let signal = Arc::new(Self::new());
+
+        let mut shared = SHARED.lock().unwrap();
+        shared.retain(|signal| signal.strong_count() > 0);
+        shared.push(Arc::downgrade(&signal));
+
+        signal
+    }
+
+    /// Marks the tree dirty and wakes any waiting engine
+    pub fn request(&self) {
+        *self.dirty.lock().unwrap() = true;
+        self.condvar.notify_all();
+    }
+
+    /// Blocks until a redraw is requested, then clears the request
+    ///
+    /// Returns early with `false` if `timeout` elapses first.
+    pub fn wait(&self, timeout: Option<Duration>) -> bool {
+        let dirty = self.dirty.lock().unwrap();
+
+        let mut dirty = match timeout {
+            Some(timeout) => {
+                self.condvar
+                    .wait_timeout_while(dirty, timeout, |dirty| !*dirty)
+                    .unwrap()
+                    .0
+            }
+            None => self.condvar.wait_while(dirty, |dirty| !*dirty).unwrap(),
+        };
+
+        std::mem::replace(&mut *dirty, false)
+    }
+}
+
+impl Default for RedrawSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wakes every engine with a shared signal
+pub(crate) fn redraw_all() {
+    let shared: Vec<Arc<RedrawSignal>> = SHARED
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(Weak::upgrade)
+        .collect();
+
+    for signal in shared {
+        signal.request();
+    }
+}
+
+/// Requests that every engine renders a new frame
+///
+/// Inside `batch`, the frames are requested when the batch ends. Prefer
+/// `Context::request_redraw`, which only wakes the engine of the context.
+pub fn request_redraw() {
+    if !hold_redraw(None) {
+        redraw_all();
+    }
+}
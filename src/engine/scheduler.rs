@@ -0,0 +1,330 @@
+//! # Scheduler Module
+//!
+//! Provides the queue effects run from. Every engine owns a scheduler, which
+//! its component tree reaches through the Context. Dependencies schedule
+//! their effects instead of running them, and the engine runs the queued
+//! effects in order on the render thread before every frame, or on a pool
+//! of worker threads. Trees not run by an engine share a background
+//! scheduler with its own workers. Effects scheduled inside `batch` are
+//! held back until the batch ends.
+
+use std::{
+    cell::RefCell,
+    collections::{HashSet, VecDeque},
+    sync::{Arc, Condvar, Mutex, OnceLock},
+};
+
+use super::redraw::{redraw_all, RedrawSignal};
+
+/// Effect waiting in the scheduler
+pub type EffectJob = Arc<dyn Fn() + Send + Sync>;
+
+/// Upper bound on the effects run by `run_pending`, in passes over the queue
+///
+/// Effects scheduled by running effects are run in the next pass, so
+/// effects triggering each other in a cycle still let frames render.
+const MAX_PASSES: usize = 16;
+
+/// Queue of effects waiting to run
+pub struct EffectScheduler {
+    /// Queued effects and the worker pool configuration
+    queue: Mutex<Queue>,
+    /// Notified whenever an effect is queued or the workers change
+    condvar: Condvar,
+    /// Wakes the engine running this scheduler
+    redraw: Arc<RedrawSignal>,
+    /// Whether effects run by workers wake every engine instead, for the
+    /// background scheduler
+    redraw_all: bool,
+}
+
+/// Scheduler running the effects of trees not run by an engine
+static BACKGROUND: OnceLock<Arc<EffectScheduler>> = OnceLock::new();
+
+/// State of the effect queue
+struct Queue {
+    /// Effects in the order they were first scheduled, with their identity
+    jobs: VecDeque<(usize, EffectJob)>,
+    /// Identities of the effects running, which are not started again
+    /// until they return
+    running: HashSet<usize>,
+    /// Number of worker threads, zero to run effects on the render thread
+    workers: usize,
+    /// Incremented whenever the workers change, stopping the previous ones
+    generation: usize,
+}
+
+/// Effects held back by the batches running on a thread
+#[derive(Default)]
+struct Batch {
+    /// Number of nested `batch` calls running
+    depth: usize,
+    /// Effects scheduled during the batch with their scheduler, in order
+    jobs: Vec<(Arc<EffectScheduler>, EffectJob)>,
+    /// Signals of the engines a redraw was requested from during the batch
+    redraw: Vec<Arc<RedrawSignal>>,
+    /// Whether a redraw was requested from every engine during the batch
+    redraw_all: bool,
+}
+
+thread_local! {
+    /// Batch running on this thread, if any
+    static BATCH: RefCell<Batch> = RefCell::new(Batch::default());
+}
+
+impl EffectScheduler {
+    /// Creates a new scheduler with no queued effects
+    pub fn new() -> Self {
+        Self::with_signal(RedrawSignal::shared(), false)
+    }
+
+    /// Creates a new scheduler waking engines through `redraw`
+    fn with_signal(redraw: Arc<RedrawSignal>, redraw_all: bool) -> Self {
+        Self {
+            queue: Mutex::new(Queue {
+                jobs: VecDeque::new(),
+                running: HashSet::new(),
+                workers: 0,
+                generation: 0,
+            }),
+            condvar: Condvar::new(),
+            redraw,
+            redraw_all,
+        }
+    }
+
+    /// Returns the scheduler running the effects of trees not run by an
+    /// engine
+    ///
+    /// Its effects run on one worker thread per available core and wake
+    /// every engine once they return, like a state change would.
+    pub fn background() -> &'static Arc<Self> {
+        BACKGROUND.get_or_init(|| {
+            let scheduler = Arc::new(Self::with_signal(Arc::new(RedrawSignal::new()), true));
+            let workers = std::thread::available_parallelism().map_or(1, usize::from);
+            scheduler.set_workers(workers);
+            scheduler
+        })
+    }
+
+    /// Returns the signal waking the engine running this scheduler
+    ///
+    /// Engines wait on it between frames.
+    pub fn redraw_signal(&self) -> &Arc<RedrawSignal> {
+        &self.redraw
+    }
+
+    /// Requests that the engine running this scheduler renders a new frame
+    ///
+    /// Inside `batch`, the frame is requested when the batch ends.
+    pub fn request_redraw(&self) {
+        if !hold_redraw(Some(&self.redraw)) {
+            self.redraw.request();
+        }
+    }
+
+    /// Queues an effect and requests a redraw
+    ///
+    /// An effect that is already queued is not queued again, so on the
+    /// render thread an effect runs once per frame however many times its
+    /// dependencies change. Effects are told apart by the allocation of `job`.
+    pub fn schedule(self: &Arc<Self>, job: EffectJob) {
+        let held = BATCH.with(|batch| {
+            let mut batch = batch.borrow_mut();
+            if batch.depth == 0 {
+                return false;
+            }
+            if !batch.jobs.iter().any(|(_, held)| Arc::ptr_eq(held, &job)) {
+                batch.jobs.push((self.clone(), job.clone()));
+            }
+            true
+        });
+
+        if !held {
+            self.enqueue(job);
+            self.redraw.request();
+        }
+    }
+
+    /// Adds an effect to the queue, unless it is already queued
+    fn enqueue(&self, job: EffectJob) {
+        let mut queue = self.queue.lock().unwrap();
+        let id = Arc::as_ptr(&job) as *const () as usize;
+        if !queue.jobs.iter().any(|(queued, _)| *queued == id) {
+            queue.jobs.push_back((id, job));
+            self.condvar.notify_one();
+        }
+    }
+
+    /// Runs the queued effects on the current thread, in order
+    ///
+    /// Called by the engines before rendering a frame. Effects scheduled
+    /// while running are run as well, up to a bound. Does nothing while
+    /// worker threads run the effects.
+    pub fn run_pending(&self) {
+        for _ in 0..MAX_PASSES {
+            let jobs = {
+                let mut queue = self.queue.lock().unwrap();
+                let queue = &mut *queue;
+                if queue.workers > 0 {
+                    return;
+                }
+
+                // Effects still running on stopped workers stay queued
+                let (running, jobs): (VecDeque<_>, VecDeque<_>) = std::mem::take(&mut queue.jobs)
+                    .into_iter()
+                    .partition(|(id, _)| queue.running.contains(id));
+                queue.jobs = running;
+                queue.running.extend(jobs.iter().map(|(id, _)| *id));
+                jobs
+            };
+
+            if jobs.is_empty() {
+                return;
+            }
+
+            for (id, job) in jobs {
+                job();
+                self.finish(id);
+            }
+        }
+    }
+
+    /// Marks an effect as no longer running
+    fn finish(&self, id: usize) {
+        self.queue.lock().unwrap().running.remove(&id);
+        self.condvar.notify_all();
+    }
+
+    /// Runs effects on `count` worker threads instead of the render thread
+    ///
+    /// Effects still start in the order they were scheduled, and an effect
+    /// never runs on two workers at once. Useful when effects block, which
+    /// would otherwise delay frames. A count of zero moves effects back to
+    /// the render thread.
+    pub fn set_workers(self: &Arc<Self>, count: usize) {
+        let generation = {
+            let mut queue = self.queue.lock().unwrap();
+            queue.workers = count;
+            queue.generation += 1;
+            queue.generation
+        };
+        self.condvar.notify_all();
+
+        for _ in 0..count {
+            let scheduler = self.clone();
+            std::thread::spawn(move || scheduler.work(generation));
+        }
+    }
+
+    /// Runs queued effects until the workers change
+    fn work(&self, generation: usize) {
+        loop {
+            let (id, job) = {
+                let mut queue = self.queue.lock().unwrap();
+                loop {
+                    if queue.generation != generation {
+                        return;
+                    }
+                    let next = queue
+                        .jobs
+                        .iter()
+                        .position(|(id, _)| !queue.running.contains(id));
+                    if let Some((id, job)) = next.and_then(|next| queue.jobs.remove(next)) {
+                        queue.running.insert(id);
+                        break (id, job);
+                    }
+                    queue = self.condvar.wait(queue).unwrap();
+                }
+            };
+
+            job();
+            self.finish(id);
+            if self.redraw_all {
+                redraw_all();
+            } else {
+                self.redraw.request();
+            }
+        }
+    }
+}
+
+/// Runs `f`, holding back effects and redraws until it returns
+///
+/// Every effect scheduled by state changes in `f` is queued once when the
+/// outermost batch on this thread ends, and a single frame is requested,
+/// so related updates never render half applied. Memos still see every
+/// change right away. Event handlers run in a batch automatically.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// osui::batch(|| {
+///     first.set(String::from("Ada"));
+///     last.set(String::from("Lovelace"));
+/// });
+/// ```
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    /// Ends the batch, even if `f` panics
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let (jobs, redraw, all) = BATCH.with(|batch| {
+                let mut batch = batch.borrow_mut();
+                batch.depth -= 1;
+                if batch.depth > 0 {
+                    return (Vec::new(), Vec::new(), false);
+                }
+                (
+                    std::mem::take(&mut batch.jobs),
+                    std::mem::take(&mut batch.redraw),
+                    std::mem::take(&mut batch.redraw_all),
+                )
+            });
+
+            for (scheduler, job) in jobs {
+                scheduler.enqueue(job);
+                scheduler.redraw.request();
+            }
+            for signal in redraw {
+                signal.request();
+            }
+            if all {
+                redraw_all();
+            }
+        }
+    }
+
+    BATCH.with(|batch| batch.borrow_mut().depth += 1);
+    let _guard = Guard;
+
+    f()
+}
+
+/// Holds back a redraw if a batch is running on this thread
+///
+/// `signal` is the engine the redraw is requested from, or `None` for
+/// every engine. Returns whether the redraw was held back.
+pub(crate) fn hold_redraw(signal: Option<&Arc<RedrawSignal>>) -> bool {
+    BATCH.with(|batch| {
+        let mut batch = batch.borrow_mut();
+        if batch.depth == 0 {
+            return false;
+        }
+        match signal {
+            Some(signal) if !batch.redraw.iter().any(|held| Arc::ptr_eq(held, signal)) => {
+                batch.redraw.push(signal.clone())
+            }
+            Some(_) => {}
+            None => batch.redraw_all = true,
+        }
+        true
+    })
+}
+
+impl Default for EffectScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
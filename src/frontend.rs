@@ -7,7 +7,7 @@
 use std::sync::Arc;
 
 use crate::component::{context::Context, scope::Scope};
-use crate::{render::Point, hooks::HookDependency, View};
+use crate::{render::Point, hooks::{rendering, HookDependency}, View};
 
 /// Trait for converting values to RSX
 pub trait ToRsx {
@@ -70,14 +70,11 @@ impl Rsx {
 
     /// Generates child components within the given context
     pub fn generate_children(&self, context: &Arc<Context>) {
-        let executor = context.get_executor();
-
         for scope in &self.0 {
             match scope {
                 RsxScope::Static(scope_fn) => {
-                    let scope = Scope::new(executor.clone());
-                    (scope_fn)(&scope);
-                    context.add_scope(scope)
+                    let scope = context.scope();
+                    rendering(None, || (scope_fn)(&scope));
                 }
                 RsxScope::Dynamic(drawer, dependencies) => {
                     let drawer = drawer.clone();
@@ -105,6 +102,12 @@ impl Rsx {
     }
 }
 
+impl Default for Rsx {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ToRsx for &Rsx {
     fn to_rsx(&self) -> Rsx {
         Rsx(self.0.clone())
@@ -1,36 +1,163 @@
 //! # Effect hooks module
 //!
 //! Provides React-like use_effect hooks for managing effects.
-//! This module includes use_effect and use_sync_effect.
+//! This module includes use_effect, use_effect_cleanup and use_sync_effect.
 
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
 
-use super::{state::State, HookDependency, HookEffect};
-use crate::component::context::Context;
+use super::{slots::current_context, state::State, use_hook, HookDependency, HookEffect};
+use crate::{
+    component::context::Context,
+    engine::{EffectJob, EffectScheduler},
+};
 
-/// Executes a function when dependencies change
+/// Function undoing what an effect did
+type Cleanup = Box<dyn FnOnce() + Send>;
+
+/// Effect function returning its cleanup
+type EffectFn = Box<dyn FnMut() -> Cleanup + Send + Sync>;
+
+/// Effect of a component, kept across renders
+#[derive(Clone)]
+struct EffectSlot {
+    /// Effect function of the latest render
+    run: Arc<Mutex<EffectFn>>,
+    /// Cleanup returned by the last run of the effect
+    cleanup: Arc<Mutex<Option<Cleanup>>>,
+}
+
+/// Registers a function to run through the engine when dependencies change
 ///
-/// Similar to React's useEffect. The provided function is executed
-/// when any of the dependencies change.
-pub fn use_effect<F: FnMut() + Send + Sync + 'static>(f: F, dependencies: &[&dyn HookDependency]) {
-    let f = Arc::new(Mutex::new(f));
-    let hook = HookEffect(Arc::new(Mutex::new({
-        let f = f.clone();
+/// Unlike `use_effect`, this is not a hook: every call registers a new
+/// effect, which runs until the returned HookEffect is cancelled. Changes
+/// queue the effect on `scheduler`, which runs it once per frame. Without
+/// a scheduler, the effect is queued on `EffectScheduler::background`.
+pub(crate) fn effect<F: FnMut() + Send + Sync + 'static>(
+    f: F,
+    dependencies: &[&dyn HookDependency],
+    scheduler: Option<Arc<EffectScheduler>>,
+) -> HookEffect {
+    let f = Mutex::new(f);
+    let active = Arc::new(AtomicBool::new(true));
+    let job: EffectJob = Arc::new({
+        let active = active.clone();
         move || {
-            let f = f.clone();
-            std::thread::spawn(move || (f.lock().unwrap())());
+            // Cancelled effects may still be queued
+            if active.load(Ordering::Acquire) {
+                (f.lock().unwrap())();
+            }
         }
-    })));
+    });
+    let scheduler = scheduler.unwrap_or_else(|| EffectScheduler::background().clone());
+    let hook = HookEffect(
+        Arc::new(Mutex::new(move || scheduler.schedule(job.clone()))),
+        active,
+    );
 
     for d in dependencies {
         d.on_update(hook.clone());
     }
+
+    hook
+}
+
+/// Executes a function when dependencies change
+///
+/// Similar to React's useEffect. The provided function is executed
+/// when any of the dependencies change. Called while a component renders,
+/// the effect is registered once and stops when the component unmounts;
+/// re-renders replace the function that runs.
+pub fn use_effect<F: FnMut() + Send + Sync + 'static>(
+    mut f: F,
+    dependencies: &[&dyn HookDependency],
+) {
+    use_effect_cleanup(
+        move || {
+            f();
+            || {}
+        },
+        dependencies,
+    );
+}
+
+/// Executes a function when dependencies change, undoing it afterwards
+///
+/// Like `use_effect`, but the function returns a cleanup, which runs
+/// before the effect runs again and when the component unmounts.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use_effect_cleanup(
+///     move || {
+///         let timer = start_timer(interval.get_dl());
+///         move || timer.stop()
+///     },
+///     &[&interval],
+/// );
+/// ```
+pub fn use_effect_cleanup<F, C>(mut f: F, dependencies: &[&dyn HookDependency])
+where
+    F: FnMut() -> C + Send + Sync + 'static,
+    C: FnOnce() + Send + 'static,
+{
+    let mut created = false;
+    let slot = use_hook(|| {
+        created = true;
+        EffectSlot {
+            run: Arc::new(Mutex::new(Box::new(|| -> Cleanup { Box::new(|| {}) }))),
+            cleanup: Arc::new(Mutex::new(None)),
+        }
+    });
+
+    *slot.run.lock().unwrap() = Box::new(move || -> Cleanup { Box::new(f()) });
+
+    if !created {
+        return;
+    }
+
+    let cx = current_context();
+    let hook = effect(
+        {
+            let slot = slot.clone();
+            move || slot.run_once()
+        },
+        dependencies,
+        cx.as_ref().and_then(Context::get_scheduler),
+    );
+
+    if let Some(cx) = cx {
+        cx.on_unmount(move || {
+            hook.cancel();
+            if let Some(cleanup) = slot.cleanup.lock().unwrap().take() {
+                cleanup();
+            }
+        });
+    }
+}
+
+impl EffectSlot {
+    /// Cleans up after the previous run and runs the effect again
+    fn run_once(&self) {
+        if let Some(cleanup) = self.cleanup.lock().unwrap().take() {
+            cleanup();
+        }
+
+        let cleanup = (self.run.lock().unwrap())();
+        *self.cleanup.lock().unwrap() = Some(cleanup);
+    }
 }
 
 /// Synchronizes state changes back to the context as events
 ///
 /// Creates an effect that emits an event whenever the state changes.
-/// The encoder function converts state values to events.
+/// The encoder function converts state values to events. Effects are
+/// deduplicated per frame, so however often the dependencies change
+/// between two frames, one event carrying the latest value is emitted,
+/// in the order the effects were first triggered.
 pub fn use_sync_effect<
     T: Send + Sync + 'static,
     Ev: Send + Sync + 'static,
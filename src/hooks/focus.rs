@@ -0,0 +1,32 @@
+//! # Focus hooks module
+//!
+//! Provides the use_focus hook for components that receive keyboard input.
+
+use std::sync::Arc;
+
+use super::state::{use_state, State};
+use crate::component::{
+    context::Context,
+    focus::{FocusGained, FocusLost},
+};
+
+/// Makes the component focusable and tracks whether it has focus
+///
+/// Returns a State that is `true` while the component is focused.
+pub fn use_focus(cx: &Arc<Context>) -> State<bool> {
+    cx.focusable();
+
+    let focused = use_state(cx.is_focused());
+
+    cx.on_event({
+        let focused = focused.clone();
+        move |_, _: &FocusGained| focused.set(true)
+    });
+
+    cx.on_event({
+        let focused = focused.clone();
+        move |_, _: &FocusLost| focused.set(false)
+    });
+
+    focused
+}
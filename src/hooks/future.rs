@@ -0,0 +1,327 @@
+//! # Future hooks module
+//!
+//! Provides hooks running futures on a pluggable executor.
+//! This module includes Spawner, use_future and use_resource.
+//! Enabled with the `async` feature.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+    },
+    task::{Context as TaskContext, Poll, Wake, Waker},
+    thread::Thread,
+};
+
+use super::{
+    state::{use_state, State},
+    use_hook, HookDependency, HookEffect,
+};
+use crate::component::context::Context;
+
+/// Future spawned by the future hooks
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Executor driving the futures of `use_future` and `use_resource`
+///
+/// The hooks use the spawner provided by the nearest ancestor with
+/// `cx.provide::<Arc<dyn Spawner>>(...)`, and a `ThreadSpawner` when
+/// none is provided. Closures taking a `BoxFuture` are spawners too.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let handle = tokio::runtime::Handle::current();
+/// cx.provide::<Arc<dyn Spawner>>(Arc::new(move |future| {
+///     handle.spawn(future);
+/// }));
+/// ```
+pub trait Spawner: Send + Sync {
+    /// Runs a future to completion in the background
+    fn spawn(&self, future: BoxFuture);
+}
+
+impl<F: Fn(BoxFuture) + Send + Sync> Spawner for F {
+    fn spawn(&self, future: BoxFuture) {
+        self(future)
+    }
+}
+
+/// Spawner running every future on a new thread
+///
+/// Needs no runtime, but futures relying on one (such as tokio I/O)
+/// must be driven by a spawner of that runtime instead.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadSpawner;
+
+impl Spawner for ThreadSpawner {
+    fn spawn(&self, future: BoxFuture) {
+        std::thread::spawn(move || block_on(future));
+    }
+}
+
+/// Wakes a thread parked in `block_on`
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls a future on the current thread until it completes
+fn block_on(mut future: BoxFuture) {
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = TaskContext::from_waker(&waker);
+
+    while future.as_mut().poll(&mut cx).is_pending() {
+        std::thread::park();
+    }
+}
+
+/// Progress of a future started by `use_resource`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resource<T, E> {
+    /// The future is running
+    Loading,
+    /// The future completed successfully
+    Ready(T),
+    /// The future failed
+    Error(E),
+}
+
+impl<T, E> Resource<T, E> {
+    /// Checks whether the future is still running
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Resource::Loading)
+    }
+
+    /// Gets the value of a successfully completed future
+    pub fn ready(&self) -> Option<&T> {
+        match self {
+            Resource::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets the error of a failed future
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            Resource::Error(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Cancellation handle of a spawned future
+#[derive(Clone, Default)]
+struct Task {
+    /// Whether the future was cancelled
+    cancelled: Arc<AtomicBool>,
+    /// Waker of the last poll, woken to drop a cancelled future promptly
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl Task {
+    /// Stops the future at its next poll
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Wraps a future so that it completes early once cancelled
+    fn wrap<F: Future<Output = ()> + Send + 'static>(&self, future: F) -> BoxFuture {
+        let task = self.clone();
+        let mut future = Box::pin(future);
+
+        Box::pin(std::future::poll_fn(move |cx| {
+            if task.is_cancelled() {
+                return Poll::Ready(());
+            }
+            *task.waker.lock().unwrap() = Some(cx.waker().clone());
+            future.as_mut().poll(cx)
+        }))
+    }
+}
+
+/// Function starting the future of a hook
+type StartFn = Box<dyn FnMut() -> BoxFuture + Send + Sync>;
+
+/// Future hook kept across renders
+#[derive(Clone)]
+struct FutureSlot {
+    /// Starts the future of the latest render
+    start: Arc<Mutex<StartFn>>,
+    /// The running future, if any
+    task: Arc<Mutex<Option<Task>>>,
+    /// Executor the futures are spawned on
+    spawner: Arc<dyn Spawner>,
+    /// The component running the futures, redrawn when one finishes
+    cx: Weak<Context>,
+}
+
+impl FutureSlot {
+    /// Cancels the running future and spawns a new one
+    fn restart(&self) {
+        let task = Task::default();
+        if let Some(previous) = self.task.lock().unwrap().replace(task.clone()) {
+            previous.cancel();
+        }
+
+        // Started on the executor, as dependencies may still be locked here
+        let start = self.start.clone();
+        let cx = self.cx.clone();
+        self.spawner.spawn(task.wrap(async move {
+            let future = (start.lock().unwrap())();
+            future.await;
+            if let Some(cx) = cx.upgrade() {
+                cx.request_redraw();
+            }
+        }));
+    }
+
+    /// Cancels the running future
+    fn cancel(&self) {
+        if let Some(task) = self.task.lock().unwrap().take() {
+            task.cancel();
+        }
+    }
+}
+
+/// Runs a future when the component mounts and when dependencies change
+///
+/// The future is spawned on the provided `Spawner`. Starting it again
+/// cancels the previous run, as does unmounting the component: a
+/// cancelled future is dropped at its next poll.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use_future(
+///     cx,
+///     {
+///         let (query, results) = (query.clone(), results.clone());
+///         move || {
+///             let (query, results) = (query.get_dl(), results.clone());
+///             async move { results.set(search(&query).await) }
+///         }
+///     },
+///     &[&query],
+/// );
+/// ```
+pub fn use_future<F, Fut>(cx: &Arc<Context>, mut f: F, dependencies: &[&dyn HookDependency])
+where
+    F: FnMut() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let mut created = false;
+    let slot = use_hook(|| {
+        created = true;
+        FutureSlot {
+            start: Arc::new(Mutex::new(Box::new(|| -> BoxFuture { Box::pin(async {}) }))),
+            task: Arc::new(Mutex::new(None)),
+            spawner: cx
+                .consume::<Arc<dyn Spawner>>()
+                .unwrap_or_else(|| Arc::new(ThreadSpawner)),
+            cx: Arc::downgrade(cx),
+        }
+    });
+
+    *slot.start.lock().unwrap() = Box::new(move || -> BoxFuture { Box::pin(f()) });
+
+    if !created {
+        return;
+    }
+
+    let hook = HookEffect::new({
+        let slot = slot.clone();
+        move || slot.restart()
+    });
+    for d in dependencies {
+        d.on_update(hook.clone());
+    }
+
+    cx.on_unmount({
+        let slot = slot.clone();
+        move || {
+            hook.cancel();
+            slot.cancel();
+        }
+    });
+
+    slot.restart();
+}
+
+/// Loads a value with a future, tracking its progress in a State
+///
+/// Like `use_future`, the future runs when the component mounts and when
+/// dependencies change, cancelling the previous run. The returned State
+/// is `Resource::Loading` while it runs, then holds its result; use it
+/// as a `%dep` to re-render when the result arrives.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let user = use_resource(
+///     cx,
+///     {
+///         let id = id.clone();
+///         move || fetch_user(id.get_dl())
+///     },
+///     &[&id],
+/// );
+///
+/// rsx! {
+///     %user match &*user.get() {
+///         Resource::Loading => { "Loading..." }
+///         Resource::Ready(user) => { "Hello, {user.name}" }
+///         Resource::Error(error) => { "Failed: {error}" }
+///     }
+/// }
+/// ```
+pub fn use_resource<T, E, F, Fut>(
+    cx: &Arc<Context>,
+    mut f: F,
+    dependencies: &[&dyn HookDependency],
+) -> State<Resource<T, E>>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+    F: FnMut() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+{
+    let resource = use_state(Resource::Loading);
+
+    use_future(
+        cx,
+        {
+            let resource = resource.clone();
+            move || {
+                let resource = resource.clone();
+                let future = f();
+
+                if !resource.get().is_loading() {
+                    resource.set(Resource::Loading);
+                }
+
+                async move {
+                    resource.set(match future.await {
+                        Ok(value) => Resource::Ready(value),
+                        Err(error) => Resource::Error(error),
+                    });
+                }
+            }
+        },
+        dependencies,
+    );
+
+    resource
+}
@@ -0,0 +1,218 @@
+//! # Memo hooks module
+//!
+//! Provides derived, read-only state that is recomputed from other states.
+//! This module includes Memo, use_memo and the computed! macro.
+
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use super::{
+    slots::current_context,
+    state::{notify, State},
+    use_hook, HookDependency, HookEffect,
+};
+
+/// Function computing the value of a memo
+type ComputeFn<T> = Arc<dyn Fn() -> T + Send + Sync>;
+
+/// Read-only state derived from other states
+///
+/// Created with `use_memo`, `State::map` or `computed!`. The value is
+/// recomputed lazily, the first time it is read after one of its inputs
+/// changed. A Memo is a `HookDependency` itself, so it can be used as a
+/// `%dep` in `rsx!` or as an input of other memos.
+pub struct Memo<T> {
+    /// The last computed value
+    value: Arc<Mutex<T>>,
+    /// Whether an input changed since the value was computed
+    dirty: Arc<AtomicBool>,
+    /// Function computing the value
+    compute: Arc<Mutex<ComputeFn<T>>>,
+    /// Functions to call when an input changes
+    dependents: Arc<Mutex<Vec<HookEffect>>>,
+}
+
+/// Guard for reading the value of a memo
+pub struct MemoRef<'a, T>(MutexGuard<'a, T>);
+
+impl<T> Memo<T> {
+    /// Acquires a lock on the value, recomputing it if it is outdated
+    pub fn get(&self) -> MemoRef<'_, T> {
+        if self.dirty.swap(false, Ordering::AcqRel) {
+            // Computed before locking, as it may read other states
+            let compute = self.compute.lock().unwrap().clone();
+            let value = compute();
+            *self.value.lock().unwrap() = value;
+        }
+
+        MemoRef(self.value.lock().unwrap())
+    }
+}
+
+impl<T: Clone> Memo<T> {
+    /// Gets a cloned copy of the value, recomputing it if it is outdated
+    ///
+    /// Like `State::get_dl`, recommended when cloning is acceptable.
+    pub fn get_dl(&self) -> T {
+        self.get().clone()
+    }
+}
+
+/// Clones the Memo handle (not the value)
+impl<T> Clone for Memo<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            dirty: self.dirty.clone(),
+            compute: self.compute.clone(),
+            dependents: self.dependents.clone(),
+        }
+    }
+}
+
+impl<T: Display> Display for Memo<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", *self.get())
+    }
+}
+
+impl<T> Deref for MemoRef<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Send + Sync> HookDependency for Memo<T> {
+    fn on_update(&self, hook: HookEffect) {
+        let mut dependents = self.dependents.lock().unwrap();
+        dependents.retain(HookEffect::is_active);
+        dependents.push(hook);
+    }
+}
+
+impl<T: Send + Sync + 'static> State<T> {
+    /// Derives a read-only state from this one
+    ///
+    /// A hook: shorthand for `use_memo` with this state as the only input.
+    pub fn map<U: Send + Sync + 'static, F: Fn(&T) -> U + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> Memo<U> {
+        let state = self.clone();
+        use_memo(move || f(&state.get()), &[self])
+    }
+}
+
+impl<T: Send + Sync + 'static> Memo<T> {
+    /// Derives a read-only state from this one
+    ///
+    /// A hook: shorthand for `use_memo` with this memo as the only input.
+    pub fn map<U: Send + Sync + 'static, F: Fn(&T) -> U + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> Memo<U> {
+        let memo = self.clone();
+        use_memo(move || f(&memo.get()), &[self])
+    }
+}
+
+/// Computes a value from other states, recomputing it when they change
+///
+/// Similar to React's useMemo. `f` runs once on the first render, and
+/// again when the value is read after any of the dependencies changed.
+/// Re-renders of the calling component replace `f` without recomputing
+/// the value; the new `f` is used once a dependency changes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let visible = use_memo(
+///     {
+///         let (todos, filter) = (todos.clone(), filter.clone());
+///         move || filter.get().apply(&todos.get())
+///     },
+///     &[&todos, &filter],
+/// );
+/// ```
+pub fn use_memo<T: Send + Sync + 'static, F: Fn() -> T + Send + Sync + 'static>(
+    f: F,
+    dependencies: &[&dyn HookDependency],
+) -> Memo<T> {
+    let f: ComputeFn<T> = Arc::new(f);
+    let mut created = false;
+    let memo = use_hook(|| {
+        created = true;
+        Memo {
+            value: Arc::new(Mutex::new(f())),
+            dirty: Arc::new(AtomicBool::new(false)),
+            compute: Arc::new(Mutex::new(f.clone())),
+            dependents: Arc::new(Mutex::new(Vec::new())),
+        }
+    });
+
+    if !created {
+        *memo.compute.lock().unwrap() = f;
+        return memo;
+    }
+
+    // Only marks the value outdated, so inputs can notify while locked
+    let hook = HookEffect::new({
+        let memo = memo.clone();
+        move || {
+            memo.dirty.store(true, Ordering::Release);
+            notify(&memo.dependents);
+        }
+    });
+
+    for d in dependencies {
+        d.on_update(hook.clone());
+    }
+
+    if let Some(cx) = current_context() {
+        cx.on_unmount(move || hook.cancel());
+    }
+
+    memo
+}
+
+/// Derives a read-only state from the named states
+///
+/// Expands to a `use_memo` depending on every named state or memo. In
+/// the expression, each name refers to the current value of its state.
+///
+/// Every named state stays locked while the expression runs, so a name
+/// must not be listed twice, and the expression must not read or set
+/// the named states through other handles; both deadlock.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let count = use_state(3);
+/// let total = use_state(10);
+/// let progress = computed!(count, total => format!("{count}/{total}"));
+/// ```
+#[macro_export]
+macro_rules! computed {
+    ($($dep:ident),+ => $body:expr) => {
+        $crate::hooks::use_memo(
+            {
+                $(let $dep = $dep.clone();)+
+                move || {
+                    $(
+                        let guard = $dep.get();
+                        let $dep = &*guard;
+                    )+
+                    $body
+                }
+            },
+            &[$(&$dep as &dyn $crate::hooks::HookDependency),+],
+        )
+    };
+}
@@ -1,23 +1,44 @@
 //! # State Management and Hooks Module
 //!
 //! Provides React-like hooks for managing component state and side effects.
-//! This module includes useState, useEffect, useMount, and state synchronization hooks.
+//! This module includes useState, useEffect, useMemo, useMount, useUnmount,
+//! useFocus, useContext, useStore, and state synchronization hooks.
 
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
 };
 
 mod effect;
+mod focus;
+#[cfg(feature = "async")]
+mod future;
+mod memo;
 mod mount;
+mod provider;
+mod slots;
 mod state;
+mod store;
 pub use effect::*;
+pub use focus::*;
+#[cfg(feature = "async")]
+pub use future::*;
+pub use memo::*;
 pub use mount::*;
+pub use provider::*;
+pub use slots::*;
 pub use state::*;
+pub use store::*;
 
 /// Effect callback that can be triggered by state changes
+///
+/// Once cancelled, calling the effect does nothing and dependencies
+/// drop it the next time they update.
 #[derive(Clone)]
-pub struct HookEffect(Arc<Mutex<dyn FnMut() + Send + Sync>>);
+pub struct HookEffect(Arc<Mutex<dyn FnMut() + Send + Sync>>, Arc<AtomicBool>);
 
 /// Trait for values that can be tracked as dependencies in hooks
 pub trait HookDependency: Send + Sync {
@@ -25,15 +46,33 @@ pub trait HookDependency: Send + Sync {
     fn on_update(&self, hook: HookEffect);
 }
 
+impl<D: HookDependency + ?Sized> HookDependency for &D {
+    fn on_update(&self, hook: HookEffect) {
+        (**self).on_update(hook)
+    }
+}
+
 impl HookEffect {
     /// Creates a new effect from a function
-    pub fn new<F: Fn() + Send + Sync + 'static>(f: F) -> Self {
-        Self(Arc::new(Mutex::new(f)))
+    pub fn new<F: FnMut() + Send + Sync + 'static>(f: F) -> Self {
+        Self(Arc::new(Mutex::new(f)), Arc::new(AtomicBool::new(true)))
     }
 
-    /// Executes the effect function
+    /// Executes the effect function, unless the effect is cancelled
     pub fn call(&self) {
-        (self.0.lock().unwrap())()
+        if self.is_active() {
+            (self.0.lock().unwrap())()
+        }
+    }
+
+    /// Stops the effect from running again
+    pub fn cancel(&self) {
+        self.1.store(false, Ordering::Release);
+    }
+
+    /// Checks whether the effect has not been cancelled
+    pub fn is_active(&self) -> bool {
+        self.1.load(Ordering::Acquire)
     }
 }
 
@@ -1,14 +1,14 @@
 //! # Mount hooks module
 //!
 //! Provides React-like Mount hooks for managing effects.
-//! This module includes Mount, use_mount and use_mount_manual.
+//! This module includes Mount, use_mount, use_mount_manual and use_unmount.
 
 use std::{
     fmt::Debug,
     sync::{Arc, Mutex},
 };
 
-use super::{HookDependency, HookEffect};
+use super::{slots::current_context, use_hook, HookDependency, HookEffect};
 
 /// Mount lifecycle hook
 ///
@@ -22,7 +22,7 @@ pub struct Mount(Arc<Mutex<bool>>, Arc<Mutex<Vec<HookEffect>>>);
 /// Returns a Mount that tracks component lifecycle and executes
 /// effects after mounting.
 pub fn use_mount() -> Mount {
-    Mount(Arc::new(Mutex::new(true)), Arc::new(Mutex::new(Vec::new())))
+    use_hook(|| Mount(Arc::new(Mutex::new(true)), Arc::new(Mutex::new(Vec::new()))))
 }
 
 /// Creates a manual mount lifecycle hook
@@ -30,10 +30,12 @@ pub fn use_mount() -> Mount {
 /// Similar to use_mount but the component starts as unmounted.
 /// Must call .mount() to trigger mounted effects.
 pub fn use_mount_manual() -> Mount {
-    Mount(
-        Arc::new(Mutex::new(false)),
-        Arc::new(Mutex::new(Vec::new())),
-    )
+    use_hook(|| {
+        Mount(
+            Arc::new(Mutex::new(false)),
+            Arc::new(Mutex::new(Vec::new())),
+        )
+    })
 }
 
 impl HookDependency for Mount {
@@ -41,7 +43,9 @@ impl HookDependency for Mount {
         if *self.0.lock().unwrap() {
             hook.call();
         } else {
-            self.1.lock().unwrap().push(hook);
+            let mut pending = self.1.lock().unwrap();
+            pending.retain(HookEffect::is_active);
+            pending.push(hook);
         }
     }
 }
@@ -56,3 +60,37 @@ impl Mount {
         self.1.lock().unwrap().clear();
     }
 }
+
+/// Function run when a component unmounts
+type UnmountFn = Box<dyn FnOnce() + Send>;
+
+/// Runs a function when the calling component unmounts
+///
+/// The function from the latest render runs once, after the component's
+/// children have unmounted. Does nothing outside of a component render.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use_unmount(move || connection.close());
+/// ```
+pub fn use_unmount<F: FnOnce() + Send + 'static>(f: F) {
+    let Some(cx) = current_context() else {
+        return;
+    };
+
+    let slot = use_hook(|| {
+        let slot: Arc<Mutex<Option<UnmountFn>>> = Arc::new(Mutex::new(None));
+        cx.on_unmount({
+            let slot = slot.clone();
+            move || {
+                if let Some(f) = slot.lock().unwrap().take() {
+                    f();
+                }
+            }
+        });
+        slot
+    });
+
+    *slot.lock().unwrap() = Some(Box::new(f));
+}
@@ -0,0 +1,32 @@
+//! # Context hooks module
+//!
+//! Provides the use_context hook for reading values provided by ancestors.
+
+use std::sync::Arc;
+
+use crate::component::context::Context;
+
+/// Gets a value provided by an ancestor with `Context::provide`
+///
+/// Looks the value up by type, from this component up to the root, and
+/// returns a clone of the nearest one. Provide cheap handles such as a
+/// `State` or a `Store` to share state with the whole subtree.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[component]
+/// fn App(cx: &Arc<Context>) -> View {
+///     cx.provide(use_state(Theme::Dark));
+///     rsx! { Settings {} }.view(cx)
+/// }
+///
+/// #[component]
+/// fn Settings(cx: &Arc<Context>) -> View {
+///     let theme = use_context::<State<Theme>>(cx).expect("no theme provided");
+///     ...
+/// }
+/// ```
+pub fn use_context<T: Clone + 'static>(cx: &Arc<Context>) -> Option<T> {
+    cx.consume()
+}
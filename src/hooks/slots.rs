@@ -0,0 +1,186 @@
+//! # Hook slots module
+//!
+//! Stores the values of hooks on the Context of the component calling them,
+//! so that they survive re-renders. Hooks are told apart by the order they
+//! are called in, which must be the same on every render.
+
+use std::{any::Any, cell::RefCell, sync::Arc};
+
+use crate::component::context::Context;
+
+thread_local! {
+    /// Contexts rendering on this thread, innermost last
+    ///
+    /// `None` entries mark code that runs during a render without
+    /// belonging to it, such as the scopes of an rsx tree.
+    static RENDERING: RefCell<Vec<Option<Arc<Context>>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Hook values of a component instance, in call order
+#[derive(Default)]
+pub(crate) struct HookSlots {
+    /// Stored hook values
+    slots: Vec<Box<dyn Any + Send + Sync>>,
+    /// Index of the next hook called during the current render
+    cursor: usize,
+    /// Whether the component finished rendering at least once
+    #[cfg(debug_assertions)]
+    rendered: bool,
+    /// Names of the stored value types, for debugging mismatched order
+    #[cfg(debug_assertions)]
+    types: Vec<&'static str>,
+}
+
+/// Result of looking up the value of the next hook
+enum Lookup<T> {
+    /// Value stored by a previous render
+    Stored(T),
+    /// No value stored yet, to be stored at the given index
+    Empty(usize),
+    /// The hooks were called in a different order than on the previous render
+    #[cfg(debug_assertions)]
+    Misordered(String),
+}
+
+impl HookSlots {
+    /// Prepares for a new render of the component
+    pub(crate) fn start(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Checks that the render called every hook of the previous one
+    ///
+    /// Returns the error to report in debug builds, so that the caller can
+    /// panic once the slots are unlocked.
+    pub(crate) fn finish(&mut self) -> Result<(), String> {
+        #[cfg(debug_assertions)]
+        {
+            self.rendered = true;
+            if self.cursor != self.slots.len() {
+                return Err(format!(
+                    "component called {} hooks, but {} on its previous render; \
+                     hooks must be called in the same order on every render",
+                    self.cursor,
+                    self.slots.len(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Takes the value of the next hook, if it was stored by a previous render
+    fn next<T: Clone + 'static>(&mut self) -> Lookup<T> {
+        let index = self.cursor;
+        self.cursor += 1;
+
+        let Some(slot) = self.slots.get(index) else {
+            #[cfg(debug_assertions)]
+            if self.rendered {
+                return Lookup::Misordered(format!(
+                    "component called more than the {} hooks of its previous render; \
+                     hooks must be called in the same order on every render",
+                    self.slots.len(),
+                ));
+            }
+            return Lookup::Empty(index);
+        };
+
+        match slot.downcast_ref::<T>() {
+            Some(value) => Lookup::Stored(value.clone()),
+            #[cfg(debug_assertions)]
+            None => Lookup::Misordered(format!(
+                "hook {index} returned a {} on the previous render, now a {}; \
+                 hooks must be called in the same order on every render",
+                self.types[index],
+                std::any::type_name::<T>(),
+            )),
+            #[cfg(not(debug_assertions))]
+            None => Lookup::Empty(index),
+        }
+    }
+
+    /// Stores the value of the hook at `index`
+    fn store<T: Send + Sync + 'static>(&mut self, index: usize, value: T) {
+        if index < self.slots.len() {
+            self.slots[index] = Box::new(value);
+        } else {
+            self.slots.push(Box::new(value));
+            #[cfg(debug_assertions)]
+            self.types.push(std::any::type_name::<T>());
+        }
+    }
+
+    /// Drops every stored hook value
+    pub(crate) fn clear(&mut self) {
+        self.slots.clear();
+        self.cursor = 0;
+        #[cfg(debug_assertions)]
+        {
+            self.rendered = false;
+            self.types.clear();
+        }
+    }
+}
+
+/// Runs `f` with hooks stored on `cx`, or not stored at all for `None`
+pub(crate) fn rendering<R>(cx: Option<Arc<Context>>, f: impl FnOnce() -> R) -> R {
+    /// Pops the rendering context, even if `f` panics
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            RENDERING.with(|rendering| rendering.borrow_mut().pop());
+        }
+    }
+
+    RENDERING.with(|rendering| rendering.borrow_mut().push(cx));
+    let _guard = Guard;
+
+    f()
+}
+
+/// Gets the context of the component rendering on this thread
+pub(crate) fn current_context() -> Option<Arc<Context>> {
+    RENDERING.with(|rendering| rendering.borrow().last().cloned().flatten())
+}
+
+/// Stores a value across renders of the calling component
+///
+/// The building block of the other hooks. On the first render, `init`
+/// creates the value; later renders get back a clone of it. Values are
+/// told apart by call order, so hooks must not be called conditionally
+/// or in loops. Debug builds panic when the order changes.
+///
+/// Outside of a component render, `init` is called every time. `init`
+/// itself must not call hooks.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[component]
+/// fn Counter(cx: &Arc<Context>) -> View {
+///     // Shared by every render of this Counter
+///     let clicks = use_hook(|| Arc::new(AtomicUsize::new(0)));
+///     ...
+/// }
+/// ```
+pub fn use_hook<T: Clone + Send + Sync + 'static>(init: impl FnOnce() -> T) -> T {
+    let Some(cx) = current_context() else {
+        return init();
+    };
+
+    // Not holding the lock while `init` runs keeps it free to use the context,
+    // and panicking only once it is released keeps it from being poisoned
+    let lookup = cx.hooks.lock().unwrap().next::<T>();
+    let index = match lookup {
+        Lookup::Stored(value) => return value,
+        Lookup::Empty(index) => index,
+        #[cfg(debug_assertions)]
+        Lookup::Misordered(message) => panic!("{message}"),
+    };
+
+    let value = init();
+    cx.hooks.lock().unwrap().store(index, value.clone());
+    value
+}
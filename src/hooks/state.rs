@@ -10,8 +10,8 @@ use std::{
     sync::{Arc, Mutex, MutexGuard},
 };
 
-use super::{HookDependency, HookEffect};
-use crate::component::context::Context;
+use super::{use_hook, HookDependency, HookEffect};
+use crate::{component::context::Context, engine::request_redraw};
 
 /// State holder for reactive values
 ///
@@ -46,6 +46,17 @@ impl<T: Clone> State<T> {
 }
 
 impl<T> State<T> {
+    /// Creates a state holding `v`
+    ///
+    /// Unlike `use_state`, this is not a hook: every call creates a new
+    /// state, also when a component re-renders.
+    pub fn new(v: T) -> Self {
+        Self {
+            value: Arc::new(Mutex::new(v)),
+            dependents: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
     /// Acquires a lock on the state for read/write access
     ///
     /// Returns an Inner guard that implements Deref and DerefMut.
@@ -66,13 +77,13 @@ impl<T> State<T> {
 
     /// Notifies all dependents of an update
     pub fn update(&self) {
-        for d in self.dependents.lock().unwrap().iter() {
-            d.call();
-        }
+        notify(&self.dependents);
     }
+}
 
-    /// Clones the State handle (not the value)
-    pub fn clone(&self) -> Self {
+/// Clones the State handle (not the value)
+impl<T> Clone for State<T> {
+    fn clone(&self) -> Self {
         Self {
             dependents: self.dependents.clone(),
             value: self.value.clone(),
@@ -80,6 +91,20 @@ impl<T> State<T> {
     }
 }
 
+/// Calls the dependents of a state, dropping cancelled ones
+pub(super) fn notify(dependents: &Mutex<Vec<HookEffect>>) {
+    let active = {
+        let mut dependents = dependents.lock().unwrap();
+        dependents.retain(HookEffect::is_active);
+        dependents.clone()
+    };
+
+    for d in active {
+        d.call();
+    }
+    request_redraw();
+}
+
 impl<T: Display> Display for State<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", self.value.lock().unwrap())
@@ -91,9 +116,7 @@ impl<T: Display> Display for State<T> {
 impl<T> Drop for Inner<'_, T> {
     fn drop(&mut self) {
         if self.updated {
-            for d in self.dependents.lock().unwrap().iter() {
-                d.call();
-            }
+            notify(&self.dependents);
         }
     }
 }
@@ -114,7 +137,9 @@ impl<T> DerefMut for Inner<'_, T> {
 
 impl<T: Send + Sync> HookDependency for State<T> {
     fn on_update(&self, hook: HookEffect) {
-        self.dependents.lock().unwrap().push(hook);
+        let mut dependents = self.dependents.lock().unwrap();
+        dependents.retain(HookEffect::is_active);
+        dependents.push(hook);
     }
 }
 
@@ -144,9 +169,8 @@ pub fn use_sync_state<
 /// Creates a new state value
 ///
 /// Returns a State that can be read and written from multiple threads.
-pub fn use_state<T>(v: T) -> State<T> {
-    State {
-        value: Arc::new(Mutex::new(v)),
-        dependents: Arc::new(Mutex::new(Vec::new())),
-    }
+/// Re-renders of the calling component get back the same State, keeping
+/// its value; `v` is only used on the first render.
+pub fn use_state<T: Send + Sync + 'static>(v: T) -> State<T> {
+    use_hook(|| State::new(v))
 }
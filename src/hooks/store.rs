@@ -0,0 +1,153 @@
+//! # Store hooks module
+//!
+//! Provides a Redux-like store, updated by dispatching actions to a reducer.
+//! This module includes Store and use_store.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use super::{memo::Memo, state::State, use_hook, HookDependency, HookEffect};
+
+/// Function applying an action to the state of a store
+type Reducer<S, A> = Arc<dyn Fn(&mut S, A) + Send + Sync>;
+
+/// Actions waiting for the running dispatch, `None` while none runs
+type Pending<A> = Arc<Mutex<Option<VecDeque<A>>>>;
+
+/// State changed only by dispatching actions
+///
+/// Every change goes through the reducer, keeping the update logic of
+/// shared state in one place. Effects depending on the store run after
+/// every dispatch, like those of a `State`. Usually created once near the
+/// root and shared with `Context::provide`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// enum Action {
+///     Add(String),
+///     Remove(usize),
+/// }
+///
+/// let todos = use_store(Vec::new(), |todos: &mut Vec<String>, action| match action {
+///     Action::Add(todo) => todos.push(todo),
+///     Action::Remove(i) => {
+///         todos.remove(i);
+///     }
+/// });
+///
+/// todos.dispatch(Action::Add("Write docs".to_string()));
+/// let count = todos.select(|todos| todos.len());
+/// ```
+pub struct Store<S, A> {
+    /// The current state
+    state: State<S>,
+    /// Applies actions to the state
+    reducer: Reducer<S, A>,
+    /// Actions dispatched while the reducer runs
+    pending: Pending<A>,
+}
+
+impl<S: Send + Sync + 'static, A> Store<S, A> {
+    /// Creates a store from its initial state and reducer
+    pub fn new<R: Fn(&mut S, A) + Send + Sync + 'static>(initial: S, reducer: R) -> Self {
+        Self {
+            state: State::new(initial),
+            reducer: Arc::new(reducer),
+            pending: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Derives a read-only state from the state of the store
+    ///
+    /// A hook, like `State::map`.
+    pub fn select<U: Send + Sync + 'static, F: Fn(&S) -> U + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> Memo<U> {
+        self.state.map(f)
+    }
+}
+
+impl<S: Clone, A> Store<S, A> {
+    /// Gets a cloned copy of the state
+    pub fn get_dl(&self) -> S {
+        self.state.get_dl()
+    }
+
+    /// Applies an action to the state and notifies subscribers
+    ///
+    /// The reducer runs on a copy of the state without holding its lock,
+    /// so it may read the store. Actions dispatched while a reducer runs,
+    /// by the reducer itself or another thread, are applied in order
+    /// once it returns.
+    pub fn dispatch(&self, action: A) {
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if let Some(pending) = pending.as_mut() {
+                pending.push_back(action);
+                return;
+            }
+            *pending = Some(VecDeque::new());
+        }
+
+        let _dispatching = Dispatching(&self.pending);
+        let mut next = Some(action);
+        while let Some(action) = next {
+            let mut state = self.state.get_dl();
+            (self.reducer)(&mut state, action);
+            self.state.set(state);
+
+            let mut pending = self.pending.lock().unwrap();
+            next = pending.as_mut().and_then(VecDeque::pop_front);
+            if next.is_none() {
+                *pending = None;
+            }
+        }
+    }
+}
+
+/// Ends the running dispatch if the reducer panics
+///
+/// Without it the store would keep queueing actions for a dispatch that
+/// never finishes. Actions still queued are dropped.
+struct Dispatching<'a, A>(&'a Mutex<Option<VecDeque<A>>>);
+
+impl<A> Drop for Dispatching<'_, A> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            *self.0.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        }
+    }
+}
+
+/// Clones the Store handle (not the state)
+impl<S, A> Clone for Store<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            reducer: self.reducer.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+impl<S: Send + Sync, A: Send> HookDependency for Store<S, A> {
+    fn on_update(&self, hook: HookEffect) {
+        self.state.on_update(hook);
+    }
+}
+
+/// Creates a store kept across renders of the calling component
+///
+/// `initial` and `reducer` are only used on the first render.
+pub fn use_store<S, A, R>(initial: S, reducer: R) -> Store<S, A>
+where
+    S: Send + Sync + 'static,
+    A: Send + 'static,
+    R: Fn(&mut S, A) + Send + Sync + 'static,
+{
+    use_hook(|| Store::new(initial, reducer))
+}
@@ -0,0 +1,751 @@
+//! # Layout Module
+//!
+//! Provides the layout passes used by the `Flex` and `Grid` components.
+//! Layout is done in two steps: every child is first measured in the
+//! available area, then `FlexLayout::arrange` or `GridLayout::arrange`
+//! turns the measured sizes into areas according to each child's
+//! `FlexStyle` or `GridPlacement`. The resulting areas never leave the
+//! parent area.
+
+use crate::render::{Area, Size};
+
+/// Axis along which flex children are placed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    /// Children are placed left to right
+    #[default]
+    Row,
+    /// Children are placed top to bottom
+    Column,
+}
+
+/// Size of a child along the main axis before growing and shrinking
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Constraint {
+    /// The measured size of the child
+    #[default]
+    Auto,
+    /// A fixed number of cells
+    Length(u16),
+    /// A percentage of the available space
+    Percent(u16),
+}
+
+/// Distribution of free space along the main axis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Justify {
+    /// Children are packed at the start
+    #[default]
+    Start,
+    /// Children are packed at the end
+    End,
+    /// Children are packed in the center
+    Center,
+    /// Free space is put between the children
+    SpaceBetween,
+    /// Free space is put around every child
+    SpaceAround,
+    /// Free space is spread evenly, edges included
+    SpaceEvenly,
+}
+
+/// Placement of children along the cross axis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    /// Placed at the start of the cross axis
+    Start,
+    /// Placed at the end of the cross axis
+    End,
+    /// Centered on the cross axis
+    Center,
+    /// Takes up the whole cross axis
+    #[default]
+    Stretch,
+}
+
+/// Space between the edges of a container and its children
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Padding {
+    /// Rows above the children
+    pub top: u16,
+    /// Columns right of the children
+    pub right: u16,
+    /// Rows below the children
+    pub bottom: u16,
+    /// Columns left of the children
+    pub left: u16,
+}
+
+/// How a single child takes part in a flex layout
+///
+/// Set on a child with the `flex` view plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlexStyle {
+    /// Size along the main axis before free space is distributed
+    pub basis: Constraint,
+    /// Share of the free space the child grows by
+    pub grow: u16,
+    /// Share of the overflow the child shrinks by, weighted by its basis
+    pub shrink: u16,
+    /// Overrides the container's cross axis alignment
+    pub align_self: Option<Align>,
+}
+
+/// Settings of a flex container
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlexLayout {
+    /// Axis along which children are placed
+    pub direction: Direction,
+    /// Cells between adjacent children
+    pub gap: u16,
+    /// Space between the container edges and its children
+    pub padding: Padding,
+    /// Distribution of free space along the main axis
+    pub justify: Justify,
+    /// Placement of children along the cross axis
+    pub align: Align,
+}
+
+impl Padding {
+    /// Creates the same padding on every side
+    pub fn all(padding: u16) -> Self {
+        Self {
+            top: padding,
+            right: padding,
+            bottom: padding,
+            left: padding,
+        }
+    }
+
+    /// Creates a vertical and a horizontal padding
+    pub fn symmetric(vertical: u16, horizontal: u16) -> Self {
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    /// Shrinks `area` by the padding
+    pub fn inner(&self, area: &Area) -> Area {
+        let x = area.x.saturating_add(self.left.min(area.width));
+        let y = area.y.saturating_add(self.top.min(area.height));
+
+        Area {
+            x,
+            y,
+            width: area
+                .width
+                .saturating_sub(self.left.saturating_add(self.right)),
+            height: area
+                .height
+                .saturating_sub(self.top.saturating_add(self.bottom)),
+        }
+    }
+}
+
+impl Default for FlexStyle {
+    fn default() -> Self {
+        Self {
+            basis: Constraint::Auto,
+            grow: 0,
+            shrink: 1,
+            align_self: None,
+        }
+    }
+}
+
+impl FlexStyle {
+    /// Creates the default style: measured size, no growing, shrinking allowed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size along the main axis before free space is distributed
+    pub fn basis(mut self, basis: Constraint) -> Self {
+        self.basis = basis;
+        self
+    }
+
+    /// Sets the share of the free space the child grows by
+    pub fn grow(mut self, grow: u16) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    /// Sets the share of the overflow the child shrinks by
+    pub fn shrink(mut self, shrink: u16) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// Overrides the container's cross axis alignment
+    pub fn align_self(mut self, align: Align) -> Self {
+        self.align_self = Some(align);
+        self
+    }
+}
+
+impl FlexLayout {
+    /// Splits a size into its main and cross axis lengths
+    fn axes(&self, size: &Size) -> (u32, u32) {
+        match self.direction {
+            Direction::Row => (size.width as u32, size.height as u32),
+            Direction::Column => (size.height as u32, size.width as u32),
+        }
+    }
+
+    /// Builds an area from main and cross axis coordinates
+    fn area(&self, main: u32, cross: u32, main_len: u32, cross_len: u32) -> Area {
+        let (x, y, width, height) = match self.direction {
+            Direction::Row => (main, cross, main_len, cross_len),
+            Direction::Column => (cross, main, cross_len, main_len),
+        };
+
+        let clamp = |value: u32| value.min(u16::MAX as u32) as u16;
+
+        Area {
+            x: clamp(x),
+            y: clamp(y),
+            width: clamp(width),
+            height: clamp(height),
+        }
+    }
+
+    fn total_gap(&self, count: usize) -> u32 {
+        self.gap as u32 * count.saturating_sub(1) as u32
+    }
+
+    /// Computes the natural size of the children, padding included
+    ///
+    /// This is the size the container takes up when nothing grows.
+    pub fn measure(&self, items: &[(Size, FlexStyle)]) -> Size {
+        let mut main = self.total_gap(items.len());
+        let mut cross = 0;
+
+        for (size, style) in items {
+            let (m, c) = self.axes(size);
+            main += match style.basis {
+                Constraint::Length(length) => length as u32,
+                _ => m,
+            };
+            cross = cross.max(c);
+        }
+
+        let (main, cross) = (main.min(u16::MAX as u32), cross.min(u16::MAX as u32));
+        let size = self.area(0, 0, main, cross);
+        let padding = &self.padding;
+
+        Size {
+            width: size
+                .width
+                .saturating_add(padding.left.saturating_add(padding.right)),
+            height: size
+                .height
+                .saturating_add(padding.top.saturating_add(padding.bottom)),
+        }
+    }
+
+    /// Arranges measured children inside `area`
+    ///
+    /// Returns one area per child, in order. Children are grown into free
+    /// space, shrunk on overflow and clipped to the padded area as a last
+    /// resort.
+    pub fn arrange(&self, area: &Area, items: &[(Size, FlexStyle)]) -> Vec<Area> {
+        let inner = self.padding.inner(area);
+        let (main_len, cross_len) = self.axes(&Size {
+            width: inner.width,
+            height: inner.height,
+        });
+        let (main_start, cross_start) = match self.direction {
+            Direction::Row => (inner.x as u32, inner.y as u32),
+            Direction::Column => (inner.y as u32, inner.x as u32),
+        };
+
+        let mut sizes: Vec<u32> = items
+            .iter()
+            .map(|(size, style)| {
+                let basis = match style.basis {
+                    Constraint::Auto => self.axes(size).0,
+                    Constraint::Length(length) => length as u32,
+                    Constraint::Percent(percent) => main_len * percent as u32 / 100,
+                };
+                basis.min(main_len)
+            })
+            .collect();
+
+        let used = sizes.iter().sum::<u32>() + self.total_gap(items.len());
+        let mut free = main_len.saturating_sub(used);
+
+        if free > 0 {
+            let weights: Vec<u32> = items.iter().map(|(_, style)| style.grow as u32).collect();
+            free = distribute(&mut sizes, &weights, free);
+        } else {
+            shrink(&mut sizes, items, used.saturating_sub(main_len));
+        }
+
+        let count = items.len() as u32;
+        let (mut offset, between) = match self.justify {
+            Justify::Start => (0, 0),
+            Justify::End => (free, 0),
+            Justify::Center => (free / 2, 0),
+            Justify::SpaceBetween if count > 1 => (0, free / (count - 1)),
+            Justify::SpaceBetween => (0, 0),
+            Justify::SpaceAround => (free / count.max(1) / 2, free / count.max(1)),
+            Justify::SpaceEvenly => (free / (count + 1), free / (count + 1)),
+        };
+
+        items
+            .iter()
+            .zip(sizes)
+            .map(|((size, style), length)| {
+                let start = offset.min(main_len);
+                let length = length.min(main_len - start);
+                offset = start + length + self.gap as u32 + between;
+
+                let natural = self.axes(size).1.min(cross_len);
+                let (cross, cross_size) = match style.align_self.unwrap_or(self.align) {
+                    Align::Start => (0, natural),
+                    Align::End => (cross_len - natural, natural),
+                    Align::Center => ((cross_len - natural) / 2, natural),
+                    Align::Stretch => (0, cross_len),
+                };
+
+                self.area(main_start + start, cross_start + cross, length, cross_size)
+            })
+            .collect()
+    }
+}
+
+/// Distributes free space proportionally to the weights
+///
+/// Leftover cells from rounding go to the first weighted sizes. Returns
+/// the space left undistributed, which is all of it when no size has a
+/// weight.
+fn distribute(sizes: &mut [u32], weights: &[u32], free: u32) -> u32 {
+    let total: u32 = weights.iter().sum();
+
+    if total == 0 {
+        return free;
+    }
+
+    let mut left = free;
+    for (size, weight) in sizes.iter_mut().zip(weights) {
+        let share = (free as u64 * *weight as u64 / total as u64) as u32;
+        *size += share;
+        left -= share;
+    }
+
+    for (size, _) in sizes
+        .iter_mut()
+        .zip(weights)
+        .filter(|(_, weight)| **weight > 0)
+        .take(left as usize)
+    {
+        *size += 1;
+    }
+
+    0
+}
+
+/// Removes overflowing space from the children that shrink
+///
+/// Children shrink in proportion to their shrink factor times their size,
+/// and never below zero.
+fn shrink(sizes: &mut [u32], items: &[(Size, FlexStyle)], mut overflow: u32) {
+    while overflow > 0 {
+        let weights: Vec<u64> = sizes
+            .iter()
+            .zip(items)
+            .map(|(size, (_, style))| *size as u64 * style.shrink as u64)
+            .collect();
+        let total: u64 = weights.iter().sum();
+
+        if total == 0 {
+            return;
+        }
+
+        let mut removed = 0;
+        for (size, weight) in sizes.iter_mut().zip(&weights) {
+            let cut = (overflow as u64 * *weight / total) as u32;
+            let cut = cut.min(*size);
+            *size -= cut;
+            removed += cut;
+        }
+
+        // Rounding removed nothing, take single cells in order
+        if removed == 0 {
+            for (size, weight) in sizes.iter_mut().zip(&weights) {
+                if removed < overflow && *weight > 0 {
+                    *size -= 1;
+                    removed += 1;
+                }
+            }
+        }
+
+        overflow -= removed;
+    }
+}
+
+/// Size of a grid row or column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Track {
+    /// A fixed number of cells
+    Length(u16),
+    /// A share of the space left after all other tracks are sized
+    Fr(u16),
+    /// The size of the largest child spanning only this track
+    Auto,
+    /// At least the given number of cells, growing up to the inner track
+    MinMax(u16, Box<Track>),
+}
+
+/// Where a child is placed in a grid
+///
+/// Set on a child with the `grid_area`, `grid_cell` and `grid_span` view
+/// plugins. Rows and columns are counted from zero. Children without a
+/// position are placed in the first free cells, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridPlacement {
+    /// Named area to fill, takes precedence over the position
+    pub area: Option<String>,
+    /// Row of the top-left cell
+    pub row: Option<u16>,
+    /// Column of the top-left cell
+    pub column: Option<u16>,
+    /// Number of rows taken up
+    pub row_span: u16,
+    /// Number of columns taken up
+    pub column_span: u16,
+}
+
+/// Settings of a grid container
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GridLayout {
+    /// Row tracks; rows beyond these are sized `Track::Auto`
+    pub rows: Vec<Track>,
+    /// Column tracks; a single `Track::Fr(1)` column when empty
+    pub columns: Vec<Track>,
+    /// Named areas, one string per row with space-separated names per
+    /// column, `.` leaving a cell unnamed
+    pub areas: Vec<String>,
+    /// Cells between adjacent rows
+    pub row_gap: u16,
+    /// Cells between adjacent columns
+    pub column_gap: u16,
+    /// Space between the container edges and its children
+    pub padding: Padding,
+}
+
+impl Track {
+    /// Creates a track of at least `min` cells growing up to `max`
+    pub fn minmax(min: u16, max: Track) -> Self {
+        Track::MinMax(min, Box::new(max))
+    }
+
+    /// The flexible share of the track, if it has one
+    fn fr(&self) -> u32 {
+        match self {
+            Track::Fr(fr) => *fr as u32,
+            Track::MinMax(_, max) => max.fr(),
+            _ => 0,
+        }
+    }
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            area: None,
+            row: None,
+            column: None,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+}
+
+/// A resolved position in a grid
+#[derive(Debug, Clone, Copy)]
+struct GridSpan {
+    row: usize,
+    column: usize,
+    rows: usize,
+    columns: usize,
+}
+
+impl GridLayout {
+    /// Finds the cells covered by each named area
+    fn named_areas(&self) -> Vec<(&str, GridSpan)> {
+        let mut named: Vec<(&str, GridSpan)> = Vec::new();
+
+        for (row, line) in self.areas.iter().enumerate() {
+            for (column, name) in line.split_whitespace().enumerate() {
+                if name == "." {
+                    continue;
+                }
+
+                match named.iter_mut().find(|(n, _)| *n == name) {
+                    Some((_, span)) => {
+                        let bottom = (span.row + span.rows).max(row + 1);
+                        let right = (span.column + span.columns).max(column + 1);
+                        span.row = span.row.min(row);
+                        span.column = span.column.min(column);
+                        span.rows = bottom - span.row;
+                        span.columns = right - span.column;
+                    }
+                    None => named.push((
+                        name,
+                        GridSpan {
+                            row,
+                            column,
+                            rows: 1,
+                            columns: 1,
+                        },
+                    )),
+                }
+            }
+        }
+
+        named
+    }
+
+    /// Resolves the cells of every child, auto-placing those without one
+    fn place(&self, placements: &[Option<GridPlacement>]) -> (Vec<GridSpan>, usize, usize) {
+        let named = self.named_areas();
+        let area_columns = self
+            .areas
+            .iter()
+            .map(|line| line.split_whitespace().count())
+            .max()
+            .unwrap_or(0);
+
+        let explicit: Vec<Option<GridSpan>> = placements
+            .iter()
+            .map(|placement| {
+                let placement = placement.as_ref()?;
+
+                if let Some(name) = &placement.area {
+                    if let Some((_, span)) = named.iter().find(|(n, _)| n == name) {
+                        return Some(*span);
+                    }
+                }
+
+                Some(GridSpan {
+                    row: placement.row? as usize,
+                    column: placement.column? as usize,
+                    rows: placement.row_span.max(1) as usize,
+                    columns: placement.column_span.max(1) as usize,
+                })
+            })
+            .collect();
+
+        let columns = explicit
+            .iter()
+            .flatten()
+            .map(|span| span.column + span.columns)
+            .chain([self.columns.len(), area_columns, 1])
+            .max()
+            .unwrap_or(1);
+
+        let mut occupied: Vec<Vec<bool>> = Vec::new();
+        let occupy = |occupied: &mut Vec<Vec<bool>>, span: &GridSpan| {
+            for row in span.row..span.row + span.rows {
+                if occupied.len() <= row {
+                    occupied.resize(row + 1, vec![false; columns]);
+                }
+                for cell in &mut occupied[row][span.column..span.column + span.columns] {
+                    *cell = true;
+                }
+            }
+        };
+
+        for span in explicit.iter().flatten() {
+            occupy(&mut occupied, span);
+        }
+
+        // Auto-placed children fill the first free cells, row by row
+        let mut cursor = 0;
+        let spans: Vec<GridSpan> = explicit
+            .iter()
+            .zip(placements)
+            .map(|(span, placement)| {
+                if let Some(span) = span {
+                    return *span;
+                }
+
+                let rows = placement.as_ref().map_or(1, |p| p.row_span.max(1)) as usize;
+                let spanned = placement.as_ref().map_or(1, |p| p.column_span.max(1)) as usize;
+                let spanned = spanned.min(columns);
+
+                let fits = |occupied: &Vec<Vec<bool>>, row: usize, column: usize| {
+                    (row..row + rows).all(|r| {
+                        occupied
+                            .get(r)
+                            .is_none_or(|cells| !cells[column..column + spanned].contains(&true))
+                    })
+                };
+
+                loop {
+                    let (row, column) = (cursor / columns, cursor % columns);
+                    if column + spanned <= columns && fits(&occupied, row, column) {
+                        let span = GridSpan {
+                            row,
+                            column,
+                            rows,
+                            columns: spanned,
+                        };
+                        occupy(&mut occupied, &span);
+                        cursor += spanned;
+                        return span;
+                    }
+                    cursor += 1;
+                }
+            })
+            .collect();
+
+        let rows = spans
+            .iter()
+            .map(|span| span.row + span.rows)
+            .chain([self.rows.len(), self.areas.len()])
+            .max()
+            .unwrap_or(0);
+
+        (spans, rows, columns)
+    }
+
+    /// Arranges measured children inside `area`
+    ///
+    /// Returns one area per child, in order, clipped to the padded area.
+    pub fn arrange(&self, area: &Area, items: &[(Size, Option<GridPlacement>)]) -> Vec<Area> {
+        let inner = self.padding.inner(area);
+        let placements: Vec<Option<GridPlacement>> = items
+            .iter()
+            .map(|(_, placement)| placement.clone())
+            .collect();
+        let (spans, rows, columns) = self.place(&placements);
+
+        let column_tracks = tracks(&self.columns, columns, Track::Fr(1));
+        let row_tracks = tracks(&self.rows, rows, Track::Auto);
+
+        let widths = size_tracks(
+            &column_tracks,
+            inner.width as u32,
+            self.column_gap as u32,
+            spans
+                .iter()
+                .zip(items)
+                .map(|(span, (size, _))| (span.column, span.columns, size.width as u32)),
+        );
+        let heights = size_tracks(
+            &row_tracks,
+            inner.height as u32,
+            self.row_gap as u32,
+            spans
+                .iter()
+                .zip(items)
+                .map(|(span, (size, _))| (span.row, span.rows, size.height as u32)),
+        );
+
+        let xs = offsets(&widths, self.column_gap as u32);
+        let ys = offsets(&heights, self.row_gap as u32);
+
+        spans
+            .iter()
+            .map(|span| {
+                let (x, width) = extent(&xs, &widths, span.column, span.columns, inner.width);
+                let (y, height) = extent(&ys, &heights, span.row, span.rows, inner.height);
+
+                Area {
+                    x: inner.x.saturating_add(x),
+                    y: inner.y.saturating_add(y),
+                    width,
+                    height,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Extends the track template to `count` tracks with `implicit` ones
+fn tracks(template: &[Track], count: usize, implicit: Track) -> Vec<Track> {
+    let mut tracks = template.to_vec();
+    if tracks.len() < count {
+        tracks.resize(count, implicit);
+    }
+    tracks
+}
+
+/// Resolves the size of every track of one axis
+///
+/// `items` are the start, span and measured size of every child. Only
+/// children spanning a single track are used to size `Track::Auto`.
+fn size_tracks(
+    tracks: &[Track],
+    length: u32,
+    gap: u32,
+    items: impl Iterator<Item = (usize, usize, u32)>,
+) -> Vec<u32> {
+    let mut content = vec![0; tracks.len()];
+    for (start, span, size) in items {
+        if span == 1 {
+            content[start] = content[start].max(size);
+        }
+    }
+
+    let mut sizes: Vec<u32> = tracks
+        .iter()
+        .zip(&content)
+        .map(|(track, content)| match track {
+            Track::Length(length) => *length as u32,
+            Track::Auto => *content,
+            Track::Fr(_) => 0,
+            Track::MinMax(min, _) => *min as u32,
+        })
+        .collect();
+
+    let gaps = gap * tracks.len().saturating_sub(1) as u32;
+    let mut free = length.saturating_sub(sizes.iter().sum::<u32>() + gaps);
+
+    // Grow bounded tracks up to their maximum before sharing the rest
+    for ((size, track), content) in sizes.iter_mut().zip(tracks).zip(&content) {
+        if let Track::MinMax(_, max) = track {
+            let max = match **max {
+                Track::Length(length) => length as u32,
+                Track::Auto => *content,
+                _ => continue,
+            };
+            let added = max.saturating_sub(*size).min(free);
+            *size += added;
+            free -= added;
+        }
+    }
+
+    let weights: Vec<u32> = tracks.iter().map(Track::fr).collect();
+    distribute(&mut sizes, &weights, free);
+
+    sizes
+}
+
+/// Computes the start of every track from their sizes
+fn offsets(sizes: &[u32], gap: u32) -> Vec<u32> {
+    let mut offset = 0;
+    sizes
+        .iter()
+        .map(|size| {
+            let start = offset;
+            offset += size + gap;
+            start
+        })
+        .collect()
+}
+
+/// Computes the start and length of a span of tracks, clipped to `limit`
+fn extent(offsets: &[u32], sizes: &[u32], start: usize, span: usize, limit: u16) -> (u16, u16) {
+    let end = start + span - 1;
+    let from = offsets[start].min(limit as u32);
+    let to = (offsets[end] + sizes[end]).min(limit as u32);
+
+    (from as u16, to.saturating_sub(from) as u16)
+}
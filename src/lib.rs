@@ -19,6 +19,10 @@
 //! - [`engine`] - Rendering engine and command execution
 //! - [`frontend`] - RSX (React-like Syntax) for component definitions
 //! - [`render`] - Low-level rendering primitives
+//! - [`layout`] - Flexbox layout pass
+//! - [`buffer`] - Cell grid that frames are rasterized into
+//! - [`border`] - Borders shared by the Block component and view plugins
+//! - [`text`] - Unicode-aware text measurement
 //!
 //! ## Example
 //!
@@ -40,11 +44,17 @@ use std::sync::Arc;
 
 use crate::render::DrawContext;
 
+pub use crate::engine::batch;
+
+pub mod border;
+pub mod buffer;
 pub mod component;
 pub mod engine;
 pub mod frontend;
 pub mod hooks;
+pub mod layout;
 pub mod render;
+pub mod text;
 pub mod view_plugins;
 
 pub mod prelude {
@@ -55,13 +65,17 @@ pub mod prelude {
 
 pub mod component_prelude {
     //! Prelude module - Re-exports commonly used items for convenience
+    pub use crate::border::*;
+    pub use crate::buffer::*;
     pub use crate::component::{context::*, scope::*, *};
     pub use crate::engine::*;
     pub use crate::frontend::*;
     pub use crate::hooks::*;
+    pub use crate::layout::*;
     pub use crate::render::*;
+    pub use crate::text::*;
     pub use crate::view_plugins::*;
-    pub use crate::{sleep, Error, Result, View, ViewWrapper};
+    pub use crate::{computed, sleep, Error, Result, View, ViewWrapper};
     pub use crossterm;
     pub use osui_macros::{component, rsx};
     pub use std::sync::{Arc, Mutex};
@@ -83,6 +97,14 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     /// Error that occurs when a mutex is poisoned
     PoisonError,
+    /// Error reading from or writing to the terminal
+    Io(Arc<std::io::Error>),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(Arc::new(value))
+    }
 }
 
 impl From<std::sync::PoisonError<std::sync::MutexGuard<'_, bool>>> for Error {
@@ -4,13 +4,23 @@
 //! for drawing content to the terminal. It includes geometric primitives
 //! (Point, Area, Size) and drawing instructions.
 
-use crate::View;
+pub use crossterm::style::Color;
+
+use crate::{
+    layout::{FlexStyle, GridPlacement},
+    text::Wrap,
+    View,
+};
 
 /// Represents a drawing instruction that can be executed by the rendering engine
 #[derive(Clone)]
 pub enum DrawInstruction {
     /// Draw text at a specific point
     Text(Point, String),
+    /// Draw styled text at a specific point
+    StyledText(Point, String, Style),
+    /// Draw text wrapped to the area right of and below a point
+    Paragraph(Point, String, ParagraphOptions),
     /// Render a view within a specified area
     View(Area, View),
     /// Render a child drawing context at an offset
@@ -48,6 +58,56 @@ pub struct Area {
     pub height: u16,
 }
 
+/// Horizontal alignment of text within its area
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    /// Aligned to the left edge
+    #[default]
+    Left,
+    /// Centered
+    Center,
+    /// Aligned to the right edge
+    Right,
+    /// Stretched to both edges by widening the spaces between words;
+    /// the last line of a paragraph is aligned to the left
+    Justify,
+}
+
+/// How a paragraph is laid out in its area
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParagraphOptions {
+    /// How lines wider than the area are broken
+    pub wrap: Wrap,
+    /// Horizontal alignment of every line
+    pub alignment: Alignment,
+    /// Number of wrapped lines scrolled out of view at the top
+    pub scroll: u16,
+    /// Whether cut off text ends with `…`
+    pub ellipsis: bool,
+    /// Style of the text
+    pub style: Style,
+}
+
+/// Visual style of drawn text
+///
+/// Colors left as `None` fall back to the terminal defaults. Attributes
+/// are only ever enabled by a style, never disabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    /// Foreground (text) color
+    pub fg: Option<Color>,
+    /// Background color
+    pub bg: Option<Color>,
+    /// Bold text
+    pub bold: bool,
+    /// Italic text
+    pub italic: bool,
+    /// Underlined text
+    pub underline: bool,
+    /// Swapped foreground and background colors
+    pub reverse: bool,
+}
+
 /// Context for drawing operations
 ///
 /// Accumulates drawing instructions that are executed by the rendering engine.
@@ -60,6 +120,10 @@ pub struct DrawContext {
     pub allocated: Area,
     /// List of drawing instructions to execute
     pub drawing: Vec<DrawInstruction>,
+    /// How the view takes part in a flex layout, set by the `flex` plugin
+    pub flex: Option<FlexStyle>,
+    /// Where the view is placed in a grid, set by the `grid_*` plugins
+    pub grid: Option<GridPlacement>,
 }
 
 impl DrawContext {
@@ -74,6 +138,8 @@ impl DrawContext {
                 height: 0,
             },
             drawing: Vec::new(),
+            flex: None,
+            grid: None,
         }
     }
 
@@ -104,6 +170,21 @@ impl DrawContext {
             .push(DrawInstruction::Text(point, text.to_string()));
     }
 
+    /// Draws styled text at the specified point
+    pub fn draw_styled_text(&mut self, point: Point, text: &str, style: Style) {
+        self.drawing
+            .push(DrawInstruction::StyledText(point, text.to_string(), style));
+    }
+
+    /// Draws a paragraph at the specified point
+    ///
+    /// The text is wrapped and aligned to the part of the area right of
+    /// and below the point when rendered.
+    pub fn draw_paragraph(&mut self, point: Point, text: &str, options: ParagraphOptions) {
+        self.drawing
+            .push(DrawInstruction::Paragraph(point, text.to_string(), options));
+    }
+
     /// Draws a view within the specified area
     pub fn draw_view(&mut self, area: Area, view: View) {
         self.drawing.push(DrawInstruction::View(area, view));
@@ -116,12 +197,34 @@ impl DrawContext {
 }
 
 impl Area {
-    fn right(&self) -> u16 {
-        self.x + self.width
+    /// Column just right of the area
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Row just below the area
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
     }
 
-    fn bottom(&self) -> u16 {
-        self.y + self.height
+    /// Whether the cell at the given position is inside the area
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// Returns the part of this area that is also inside `other`
+    ///
+    /// The result is empty when the areas do not overlap.
+    pub fn intersection(&self, other: &Area) -> Area {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+
+        Area {
+            x,
+            y,
+            width: self.right().min(other.right()).saturating_sub(x),
+            height: self.bottom().min(other.bottom()).saturating_sub(y),
+        }
     }
 
     pub fn merge(&mut self, other: &Self) {
@@ -132,3 +235,60 @@ impl Area {
         self.height = self.bottom().max(other.bottom()) - self.y;
     }
 }
+
+impl Style {
+    /// Creates an empty style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the foreground color
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background color
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Enables bold text
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Enables italic text
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Enables underlined text
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Enables reversed colors
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Layers `other` on top of this style
+    ///
+    /// Colors set in `other` take precedence and attributes are combined.
+    pub fn patch(&self, other: &Style) -> Style {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            bold: self.bold || other.bold,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+            reverse: self.reverse || other.reverse,
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! # Text Module
+//!
+//! Provides Unicode-aware text measurement. Text is split into grapheme
+//! clusters (user-perceived characters), each taking up zero, one or two
+//! terminal columns depending on its East Asian width.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::render::{Alignment, ParagraphOptions, Size};
+
+/// Measures the number of terminal columns a grapheme cluster takes up
+///
+/// Wide characters such as CJK and emoji take up two columns, combining
+/// marks add nothing to the character they belong to.
+pub fn grapheme_width(grapheme: &str) -> u16 {
+    grapheme.width().min(2) as u16
+}
+
+/// Measures the number of terminal columns a line of text takes up
+pub fn display_width(text: &str) -> u16 {
+    text.graphemes(true)
+        .map(|g| grapheme_width(g) as usize)
+        .sum::<usize>()
+        .min(u16::MAX as usize) as u16
+}
+
+/// Cuts a line of text to at most `width` columns
+///
+/// Never splits a grapheme cluster; a wide character that would only
+/// partly fit is left out.
+pub fn truncate(text: &str, width: u16) -> &str {
+    let mut used = 0;
+
+    for (i, grapheme) in text.grapheme_indices(true) {
+        used += grapheme_width(grapheme);
+        if used > width {
+            return &text[..i];
+        }
+    }
+
+    text
+}
+
+/// Measures the size a piece of text takes up when drawn.
+///
+/// # Sizing rules
+/// - Width = display width of the widest line
+/// - Height = number of lines
+///
+/// This is the measurement used by the `*_auto` plugins, exposed for
+/// components that lay out text themselves.
+pub fn text_size(text: &str) -> Size {
+    let mut size = Size {
+        width: 0,
+        height: 0,
+    };
+
+    for line in text.lines() {
+        size.width = size.width.max(display_width(line));
+        size.height += 1;
+    }
+
+    size
+}
+
+/// How text wider than its area is broken into lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Wrap {
+    /// Lines are broken between words, words wider than the area by character
+    #[default]
+    Word,
+    /// Lines are broken at the last character that fits
+    Char,
+    /// Lines are never broken
+    None,
+}
+
+/// Breaks text into lines of at most `width` columns
+///
+/// Each returned line is paired with whether it ends a line of the
+/// original text. Word wrapping collapses the whitespace between words.
+fn wrap_lines(text: &str, width: u16, wrap: Wrap) -> Vec<(String, bool)> {
+    let mut wrapped = Vec::new();
+
+    for line in text.lines() {
+        let mut lines = Vec::new();
+
+        match wrap {
+            Wrap::None => lines.push(line.to_string()),
+            Wrap::Char => wrap_chars(line, width, &mut lines),
+            Wrap::Word => {
+                let mut current = String::new();
+
+                for word in line.split_whitespace() {
+                    let used = display_width(&current);
+
+                    if used > 0 && used + 1 + display_width(word) > width {
+                        lines.push(std::mem::take(&mut current));
+                    }
+
+                    if display_width(word) > width {
+                        wrap_chars(word, width, &mut lines);
+                        current = lines.pop().unwrap_or_default();
+                    } else {
+                        if !current.is_empty() {
+                            current.push(' ');
+                        }
+                        current.push_str(word);
+                    }
+                }
+
+                lines.push(current);
+            }
+        }
+
+        let last = lines.len() - 1;
+        wrapped.extend(lines.into_iter().enumerate().map(|(i, l)| (l, i == last)));
+    }
+
+    wrapped
+}
+
+/// Breaks a single line at the last grapheme that fits
+fn wrap_chars(line: &str, width: u16, lines: &mut Vec<String>) {
+    let mut current = String::new();
+    let mut used = 0;
+
+    for grapheme in line.graphemes(true) {
+        let w = grapheme_width(grapheme);
+        if used + w > width && used > 0 {
+            lines.push(std::mem::take(&mut current));
+            used = 0;
+        }
+        current.push_str(grapheme);
+        used += w;
+    }
+
+    lines.push(current);
+}
+
+/// Measures the size of a paragraph laid out in `width` columns
+///
+/// Lines scrolled out of view are not counted, and lines that are not
+/// wrapped count as at most `width` columns.
+pub fn paragraph_size(text: &str, options: &ParagraphOptions, width: u16) -> Size {
+    let lines = wrap_lines(text, width, options.wrap);
+    let visible = lines.get(options.scroll as usize..).unwrap_or_default();
+
+    Size {
+        width: visible
+            .iter()
+            .map(|(line, _)| display_width(line))
+            .max()
+            .unwrap_or(0)
+            .min(width),
+        height: visible.len().min(u16::MAX as usize) as u16,
+    }
+}
+
+/// Lays out a paragraph in an area of `width` by `height` cells
+///
+/// Returns the visible lines, wrapped, scrolled, truncated and aligned
+/// according to the options.
+pub fn layout_paragraph(
+    text: &str,
+    options: &ParagraphOptions,
+    width: u16,
+    height: u16,
+) -> Vec<String> {
+    let lines = wrap_lines(text, width, options.wrap);
+    let start = (options.scroll as usize).min(lines.len());
+    let end = (start + height as usize).min(lines.len());
+    let cut = end < lines.len();
+
+    lines[start..end]
+        .iter()
+        .enumerate()
+        .map(|(i, (line, ends))| {
+            let overflows = display_width(line) > width || (cut && start + i + 1 == end);
+
+            if options.ellipsis && overflows {
+                match width {
+                    0 => String::new(),
+                    _ => format!("{}…", truncate(line, width - 1)),
+                }
+            } else {
+                align_line(line, width, options.alignment, *ends)
+            }
+        })
+        .collect()
+}
+
+/// Pads a line to its alignment within `width` columns
+///
+/// Justified lines are stretched by widening the spaces between words,
+/// except for the line ending a paragraph.
+fn align_line(line: &str, width: u16, alignment: Alignment, ends: bool) -> String {
+    let free = width.saturating_sub(display_width(line)) as usize;
+
+    match alignment {
+        Alignment::Left => line.to_string(),
+        Alignment::Center => format!("{}{line}", " ".repeat(free / 2)),
+        Alignment::Right => format!("{}{line}", " ".repeat(free)),
+        Alignment::Justify if ends => line.to_string(),
+        Alignment::Justify => {
+            let words: Vec<&str> = line.split(' ').collect();
+            let gaps = words.len() - 1;
+
+            if gaps == 0 {
+                return line.to_string();
+            }
+
+            let mut justified = String::new();
+            for (i, word) in words.iter().enumerate() {
+                justified.push_str(word);
+                if i < gaps {
+                    let spaces = 1 + free / gaps + (i < free % gaps) as usize;
+                    justified.push_str(&" ".repeat(spaces));
+                }
+            }
+            justified
+        }
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! Provides the essential plugins for modifying a `View`
 
+use std::sync::Arc;
+
 use crate::{
-    render::{DrawContext, DrawInstruction},
+    border::Border,
+    layout::{FlexStyle, GridPlacement},
+    render::{Area, DrawContext, DrawInstruction, Point, Style},
+    text::{paragraph_size, text_size},
     View,
 };
 
@@ -12,12 +17,15 @@ use crate::{
 /// # Behavior
 /// - Modifies `ctx.allocated.x`
 /// - Does **not** modify height or width
-/// - Assumes `ctx.allocated.width <= ctx.area.width`
+/// - Aligns to the left edge when the allocation is wider than the area
 ///
 /// # Notes
 /// This function only affects positioning, not sizing.
 pub fn x_center(ctx: &mut DrawContext, _view: &View) {
-    ctx.allocated.y = (ctx.area.height - ctx.allocated.height) / 2;
+    ctx.allocated.x = ctx
+        .area
+        .x
+        .saturating_add(ctx.area.width.saturating_sub(ctx.allocated.width) / 2);
 }
 
 /// Vertically centers the allocated area within the available draw area.
@@ -25,13 +33,16 @@ pub fn x_center(ctx: &mut DrawContext, _view: &View) {
 /// # Behavior
 /// - Modifies `ctx.allocated.y`
 /// - Does **not** modify width or height
-/// - Assumes `ctx.allocated.height <= ctx.area.height`
+/// - Aligns to the top edge when the allocation is taller than the area
 ///
 /// # Typical usage
 /// Called after size has been resolved (e.g. after `size_auto`,
 /// `height_auto`, or a fixed height has been set).
 pub fn y_center(ctx: &mut DrawContext, _view: &View) {
-    ctx.allocated.y = (ctx.area.height - ctx.allocated.height) / 2;
+    ctx.allocated.y = ctx
+        .area
+        .y
+        .saturating_add(ctx.area.height.saturating_sub(ctx.allocated.height) / 2);
 }
 
 /// Centers the allocated area both horizontally and vertically.
@@ -44,8 +55,8 @@ pub fn y_center(ctx: &mut DrawContext, _view: &View) {
 /// Should generally be called **after** size resolution
 /// (e.g. `size_auto`, `width_auto`, `height_auto`).
 pub fn center(ctx: &mut DrawContext, _view: &View) {
-    ctx.allocated.x = (ctx.area.width - ctx.allocated.width) / 2;
-    ctx.allocated.y = (ctx.area.height - ctx.allocated.height) / 2;
+    x_center(ctx, _view);
+    y_center(ctx, _view);
 }
 
 /// Automatically computes both width and height based on drawn content.
@@ -54,6 +65,7 @@ pub fn center(ctx: &mut DrawContext, _view: &View) {
 /// - `Text`:
 ///   - Width = longest line length
 ///   - Height = number of lines
+/// - `Paragraph`: same as `Text`, after wrapping to the available width
 /// - `View`:
 ///   - Recursively executes the view in a fresh `DrawContext`
 ///   - Uses the child view's allocated size
@@ -66,15 +78,15 @@ pub fn center(ctx: &mut DrawContext, _view: &View) {
 pub fn size_auto(ctx: &mut DrawContext, _view: &View) {
     for i in &ctx.drawing {
         match i {
-            DrawInstruction::Text(_, text) => {
-                let mut height = 0;
-
-                for line in text.lines() {
-                    ctx.allocated.width = ctx.allocated.width.max(line.len() as u16);
-                    height += 1;
-                }
-
-                ctx.allocated.height = height;
+            DrawInstruction::Text(_, text) | DrawInstruction::StyledText(_, text, _) => {
+                let size = text_size(text);
+                ctx.allocated.width = ctx.allocated.width.max(size.width);
+                ctx.allocated.height = size.height;
+            }
+            DrawInstruction::Paragraph(point, text, options) => {
+                let size = paragraph_size(text, options, ctx.area.width.saturating_sub(point.x));
+                ctx.allocated.width = ctx.allocated.width.max(size.width);
+                ctx.allocated.height = size.height;
             }
             DrawInstruction::View(area, view) => {
                 let mut c = DrawContext::new(area.clone());
@@ -98,15 +110,15 @@ pub fn size_auto_merge(ctx: &mut DrawContext, _view: &View) {
 
     for i in &ctx.drawing {
         match i {
-            DrawInstruction::Text(_, text) => {
-                let mut height = 0;
-
-                for line in text.lines() {
-                    ctx.allocated.width = ctx.allocated.width.max(line.len() as u16);
-                    height += 1;
-                }
-
-                ctx.allocated.height = height;
+            DrawInstruction::Text(_, text) | DrawInstruction::StyledText(_, text, _) => {
+                let size = text_size(text);
+                ctx.allocated.width = ctx.allocated.width.max(size.width);
+                ctx.allocated.height = size.height;
+            }
+            DrawInstruction::Paragraph(point, text, options) => {
+                let size = paragraph_size(text, options, ctx.area.width.saturating_sub(point.x));
+                ctx.allocated.width = ctx.allocated.width.max(size.width);
+                ctx.allocated.height = size.height;
             }
             DrawInstruction::View(area, view) => {
                 let mut c = DrawContext::new(area.clone());
@@ -123,6 +135,7 @@ pub fn size_auto_merge(ctx: &mut DrawContext, _view: &View) {
 ///
 /// # Sizing rules
 /// - `Text`: width is the longest line
+/// - `Paragraph`: width is the longest line after wrapping
 /// - `View`: width is taken from the child view's auto-sized result
 /// - Height is left unchanged
 ///
@@ -131,10 +144,15 @@ pub fn size_auto_merge(ctx: &mut DrawContext, _view: &View) {
 pub fn width_auto(ctx: &mut DrawContext, _view: &View) {
     for i in &ctx.drawing {
         match i {
-            DrawInstruction::Text(_, text) => {
-                for line in text.lines() {
-                    ctx.allocated.width = ctx.allocated.width.max(line.len() as u16);
-                }
+            DrawInstruction::Text(_, text) | DrawInstruction::StyledText(_, text, _) => {
+                ctx.allocated.width = ctx.allocated.width.max(text_size(text).width);
+            }
+            DrawInstruction::Paragraph(point, text, options) => {
+                let width = ctx.area.width.saturating_sub(point.x);
+                ctx.allocated.width = ctx
+                    .allocated
+                    .width
+                    .max(paragraph_size(text, options, width).width);
             }
             DrawInstruction::View(area, view) => {
                 let mut c = DrawContext::new(area.clone());
@@ -151,22 +169,23 @@ pub fn width_auto(ctx: &mut DrawContext, _view: &View) {
 ///
 /// # Sizing rules
 /// - `Text`: height is the number of lines
+/// - `Paragraph`: height is the number of lines after wrapping to the
+///   available width, without the lines scrolled out of view
 /// - `View`: height is taken from the child view's auto-sized result
 /// - Width is left unchanged
 ///
 /// # Notes
-/// This pass ignores line width and wrapping concerns.
+/// Plain `Text` is never wrapped; draw a paragraph for text that should
+/// fit the width of its area.
 pub fn height_auto(ctx: &mut DrawContext, _view: &View) {
     for i in &ctx.drawing {
         match i {
-            DrawInstruction::Text(_, text) => {
-                let mut height = 0;
-
-                for _ in text.lines() {
-                    height += 1;
-                }
-
-                ctx.allocated.height = height;
+            DrawInstruction::Text(_, text) | DrawInstruction::StyledText(_, text, _) => {
+                ctx.allocated.height = text_size(text).height;
+            }
+            DrawInstruction::Paragraph(point, text, options) => {
+                let width = ctx.area.width.saturating_sub(point.x);
+                ctx.allocated.height = paragraph_size(text, options, width).height;
             }
             DrawInstruction::View(area, view) => {
                 let mut c = DrawContext::new(area.clone());
@@ -179,6 +198,129 @@ pub fn height_auto(ctx: &mut DrawContext, _view: &View) {
     }
 }
 
+/// Sets how the view takes part in a flex layout.
+///
+/// # Behavior
+/// - Read by `Flex`, `FlexRow` and `FlexColumn` when arranging children
+/// - Has no effect outside of a flex container
+///
+/// # Notes
+/// With the default `Constraint::Auto` basis the child's allocated size is
+/// used, so it should come after size resolution (e.g. `size_auto`).
+pub fn flex(ctx: &mut DrawContext, _view: &View, style: FlexStyle) {
+    ctx.flex = Some(style);
+}
+
+/// Places the view in a named area of a grid.
+///
+/// # Behavior
+/// - Read by `Grid` when arranging children
+/// - Children naming an unknown area are placed automatically
+pub fn grid_area(ctx: &mut DrawContext, _view: &View, name: &str) {
+    ctx.grid.get_or_insert_with(GridPlacement::default).area = Some(name.to_string());
+}
+
+/// Places the view at a row and column of a grid, counted from zero.
+///
+/// # Behavior
+/// - Read by `Grid` when arranging children
+/// - Combines with `grid_span` to cover several cells
+pub fn grid_cell(ctx: &mut DrawContext, _view: &View, row: u16, column: u16) {
+    let placement = ctx.grid.get_or_insert_with(GridPlacement::default);
+    placement.row = Some(row);
+    placement.column = Some(column);
+}
+
+/// Makes the view span several rows and columns of a grid.
+///
+/// # Behavior
+/// - Read by `Grid` when arranging children
+/// - Without `grid_cell`, the view is placed in the first free cells
+pub fn grid_span(ctx: &mut DrawContext, _view: &View, rows: u16, columns: u16) {
+    let placement = ctx.grid.get_or_insert_with(GridPlacement::default);
+    placement.row_span = rows;
+    placement.column_span = columns;
+}
+
+/// Draws a border around the view.
+///
+/// # Behavior
+/// - Grows the allocated area by the border and padding, without
+///   leaving the available draw area
+/// - Clears drawing and redraws the view inside the border
+///
+/// # Order
+/// Takes the place of `redraw`, so it should be called **after** size
+/// resolution and positioning.
+pub fn border(ctx: &mut DrawContext, view: &View, border: Border) {
+    let thickness = border.thickness();
+    let allocated = &ctx.allocated;
+    let right = ctx.area.x.saturating_add(ctx.area.width);
+    let bottom = ctx.area.y.saturating_add(ctx.area.height);
+
+    let area = Area {
+        x: allocated.x,
+        y: allocated.y,
+        width: allocated
+            .width
+            .saturating_add(thickness.width)
+            .min(right.saturating_sub(allocated.x)),
+        height: allocated
+            .height
+            .saturating_add(thickness.height)
+            .min(bottom.saturating_sub(allocated.y)),
+    };
+    ctx.allocated = area.clone();
+
+    let mut frame = DrawContext::new(area.clone());
+    let inner = border.draw(&mut frame);
+    frame.draw_view(inner, view.clone());
+
+    ctx.clear();
+    ctx.draw(DrawInstruction::Child(
+        Point {
+            x: area.x.saturating_sub(ctx.area.x),
+            y: area.y.saturating_sub(ctx.area.y),
+        },
+        frame,
+    ));
+}
+
+/// Applies a style to everything drawn by the view.
+///
+/// # Behavior
+/// - Turns `Text` into `StyledText`
+/// - Styles already set on `StyledText` take precedence over `style`
+/// - Nested views are styled as well
+///
+/// # Order
+/// Can be placed anywhere, as it only changes drawing instructions.
+pub fn style(ctx: &mut DrawContext, _view: &View, style: Style) {
+    apply_style(&mut ctx.drawing, style);
+}
+
+fn apply_style(drawing: &mut [DrawInstruction], style: Style) {
+    for inst in drawing {
+        match inst {
+            DrawInstruction::Text(point, text) => {
+                *inst = DrawInstruction::StyledText(point.clone(), std::mem::take(text), style);
+            }
+            DrawInstruction::StyledText(_, _, s) => *s = style.patch(s),
+            DrawInstruction::Paragraph(_, _, options) => {
+                options.style = style.patch(&options.style)
+            }
+            DrawInstruction::View(_, view) => {
+                let inner = view.clone();
+                *view = Arc::new(move |ctx| {
+                    inner(ctx);
+                    apply_style(&mut ctx.drawing, style);
+                });
+            }
+            DrawInstruction::Child(_, child) => apply_style(&mut child.drawing, style),
+        }
+    }
+}
+
 pub fn redraw(ctx: &mut DrawContext, view: &View) {
     ctx.clear();
     ctx.draw_view(ctx.allocated.clone(), view.clone());
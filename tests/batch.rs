@@ -0,0 +1,118 @@
+use std::time::{Duration, Instant};
+
+use osui::prelude::*;
+
+/// State changes wake every engine, so tests checking that no redraw was
+/// requested must not run alongside each other
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Values seen by the runs of the effect, in order
+type Seen = Arc<Mutex<Vec<(i32, i32)>>>;
+
+#[component]
+fn Pair(_cx: &Arc<Context>, first: State<i32>, second: State<i32>, seen: Seen) -> View {
+    let (first, second, seen) = (first.clone(), second.clone(), seen.clone());
+    use_effect(
+        {
+            let (first, second) = (first.clone(), second.clone());
+            move || seen.lock().unwrap().push((first.get_dl(), second.get_dl()))
+        },
+        &[&first, &second],
+    );
+    Arc::new(|_| {})
+}
+
+fn pair() -> (State<i32>, State<i32>, Seen) {
+    (State::new(0), State::new(0), Seen::default())
+}
+
+/// Checks for a pending redraw without blocking, clearing it
+fn requested(cx: &Arc<Context>) -> bool {
+    cx.get_scheduler()
+        .unwrap()
+        .redraw_signal()
+        .wait(Some(Duration::ZERO))
+}
+
+#[test]
+fn batched_updates_run_effects_and_redraw_once() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (first, second, seen) = pair();
+    let engine = Headless::new(1, 1);
+    let cx = engine.init(Pair {
+        first: first.clone(),
+        second: second.clone(),
+        seen: seen.clone(),
+    });
+    engine.step(&cx);
+    requested(&cx);
+
+    osui::batch(|| {
+        first.set(1);
+        second.set(2);
+        first.set(3);
+        assert!(!requested(&cx));
+    });
+    assert!(requested(&cx));
+    assert!(!requested(&cx));
+
+    engine.step(&cx);
+    assert_eq!(*seen.lock().unwrap(), vec![(3, 2)]);
+}
+
+#[test]
+fn nested_batches_flush_when_the_outermost_ends() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (first, second, seen) = pair();
+    let engine = Headless::new(1, 1);
+    let cx = engine.init(Pair {
+        first: first.clone(),
+        second: second.clone(),
+        seen: seen.clone(),
+    });
+    engine.step(&cx);
+    requested(&cx);
+
+    osui::batch(|| {
+        osui::batch(|| first.set(1));
+        assert!(!requested(&cx));
+
+        engine.step(&cx);
+        assert!(seen.lock().unwrap().is_empty());
+
+        osui::batch(|| second.set(2));
+    });
+    assert!(requested(&cx));
+
+    engine.step(&cx);
+    assert_eq!(*seen.lock().unwrap(), vec![(1, 2)]);
+}
+
+#[test]
+fn batches_hold_effects_of_trees_without_an_engine() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (first, second, seen) = pair();
+    let cx = Context::new(
+        Pair {
+            first: first.clone(),
+            second: second.clone(),
+            seen: seen.clone(),
+        },
+        Headless::new(1, 1).executor(),
+    );
+    cx.refresh();
+
+    osui::batch(|| {
+        first.set(1);
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(seen.lock().unwrap().is_empty());
+        second.set(2);
+    });
+
+    let start = Instant::now();
+    while seen.lock().unwrap().is_empty() && start.elapsed() < Duration::from_secs(1) {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(*seen.lock().unwrap(), vec![(1, 2)]);
+}
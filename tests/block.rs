@@ -0,0 +1,83 @@
+use osui::prelude::*;
+
+#[component]
+fn Card(
+    cx: &Arc<Context>,
+    title: Arc<str>,
+    border_type: BorderType,
+    alignment: Alignment,
+    padding: Padding,
+) -> View {
+    let title = Some(title.to_string());
+    let (border_type, alignment, padding) = (*border_type, *alignment, *padding);
+    rsx! {
+        Block {
+            border_type: border_type,
+            title: title.clone(),
+            title_alignment: alignment,
+            padding: padding,
+            ..Default::default()
+
+            "ab"
+        }
+    }
+    .view(cx)
+}
+
+fn card(
+    width: u16,
+    height: u16,
+    title: &str,
+    border_type: BorderType,
+    alignment: Alignment,
+) -> Vec<String> {
+    let engine = Headless::new(width, height);
+    let cx = engine.init(Card {
+        title: title.into(),
+        border_type,
+        alignment,
+        padding: Padding::default(),
+    });
+    engine.step(&cx);
+    engine.lines()
+}
+
+#[test]
+fn children_are_drawn_inside_the_border() {
+    assert_eq!(
+        card(6, 3, "Hi", BorderType::Rounded, Alignment::Left),
+        vec!["╭Hi──╮", "│ab  │", "╰────╯"]
+    );
+    assert_eq!(
+        card(6, 3, "Hi", BorderType::Double, Alignment::Right),
+        vec!["╔══Hi╗", "║ab  ║", "╚════╝"]
+    );
+    assert_eq!(
+        card(6, 3, "Hi", BorderType::Ascii, Alignment::Center),
+        vec!["+-Hi-+", "|ab  |", "+----+"]
+    );
+}
+
+#[test]
+fn titles_are_measured_in_display_width() {
+    assert_eq!(
+        card(8, 3, "日本", BorderType::Plain, Alignment::Right),
+        vec!["┌──日本┐", "│ab    │", "└──────┘"]
+    );
+}
+
+#[test]
+fn padding_moves_the_children_inwards() {
+    let engine = Headless::new(8, 5);
+    let cx = engine.init(Card {
+        title: "".into(),
+        border_type: BorderType::Plain,
+        alignment: Alignment::Left,
+        padding: Padding::symmetric(1, 2),
+    });
+    engine.step(&cx);
+    assert_eq!(
+        engine.lines(),
+        vec!["┌──────┐", "│      │", "│  ab  │", "│      │", "└──────┘"]
+    );
+}
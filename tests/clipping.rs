@@ -0,0 +1,68 @@
+use osui::prelude::*;
+
+/// Draws into a child area of the given size, then a sibling to its right
+#[component]
+fn Misbehaving(_cx: &Arc<Context>, text: Arc<str>, at: (u16, u16)) -> View {
+    let (text, at) = (text.clone(), *at);
+    Arc::new(move |ctx| {
+        let mut sibling = DrawContext::new(Area {
+            x: 3,
+            y: 0,
+            width: 3,
+            height: 2,
+        });
+        sibling.draw_text(Point { x: 0, y: 0 }, "xyz");
+        ctx.draw(DrawInstruction::Child(Point { x: 3, y: 0 }, sibling));
+
+        let mut child = DrawContext::new(Area {
+            x: 0,
+            y: 0,
+            width: 3,
+            height: 1,
+        });
+        child.draw_text(Point { x: at.0, y: at.1 }, &text);
+        ctx.draw(DrawInstruction::Child(Point { x: 0, y: 0 }, child));
+    })
+}
+
+fn draw(text: &str, at: (u16, u16)) -> Vec<String> {
+    let engine = Headless::new(6, 2);
+    let cx = engine.init(Misbehaving {
+        text: text.into(),
+        at,
+    });
+    engine.step(&cx);
+    engine.lines()
+}
+
+#[test]
+fn children_cannot_draw_over_their_siblings() {
+    assert_eq!(draw("overflowing", (0, 0)), vec!["ovexyz", "      "]);
+    assert_eq!(draw("first\nsecond", (1, 0)), vec![" fixyz", "      "]);
+}
+
+#[test]
+fn far_away_drawing_is_dropped_without_overflowing() {
+    assert_eq!(draw("far", (u16::MAX, u16::MAX)), vec!["   xyz", "      "]);
+    assert_eq!(draw("far", (u16::MAX, 0)), vec!["   xyz", "      "]);
+}
+
+#[component]
+fn Boxed(cx: &Arc<Context>) -> View {
+    rsx! {
+        Block {
+            ..Default::default()
+
+            "a long line\nwith more lines\nthan fit"
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn block_contents_stay_inside_the_border() {
+    let engine = Headless::new(6, 4);
+    let cx = engine.init(Boxed {});
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["┌────┐", "│a lo│", "│with│", "└────┘"]);
+}
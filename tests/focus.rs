@@ -0,0 +1,137 @@
+use osui::prelude::*;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+/// Handlers that saw a key, in order
+type Log = Arc<Mutex<Vec<String>>>;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn typed(key: &KeyEvent) -> char {
+    match key.code {
+        KeyCode::Char(c) => c,
+        _ => '?',
+    }
+}
+
+#[component]
+fn Field(cx: &Arc<Context>, name: Arc<str>) -> View {
+    let focused = use_focus(cx);
+    let log: Log = cx.consume().unwrap();
+    cx.on_event({
+        let name = name.clone();
+        move |_, key: &KeyEvent| log.lock().unwrap().push(format!("{name} {}", typed(key)))
+    });
+
+    let name = name.clone();
+    Arc::new(move |ctx| {
+        let marker = if focused.get_dl() { '>' } else { ' ' };
+        ctx.draw_text(Point { x: 0, y: 0 }, &format!("{marker}{name}"))
+    })
+}
+
+#[component]
+fn Form(cx: &Arc<Context>, log: Log, capture: bool) -> View {
+    cx.on_event({
+        let log = log.clone();
+        move |_, key: &KeyEvent| log.lock().unwrap().push(format!("form {}", typed(key)))
+    });
+    if *capture {
+        cx.on_event_capture(|_, _: &KeyEvent, ecx| ecx.stop_propagation());
+    }
+    cx.provide(log.clone());
+
+    rsx! {
+        FlexColumn {
+            Field { name: "first".into() }
+            Field { name: "second".into() }
+        }
+    }
+    .view(cx)
+}
+
+fn logged(log: &Log) -> Vec<String> {
+    std::mem::take(&mut *log.lock().unwrap())
+}
+
+#[test]
+fn tab_cycles_focus_in_tree_order() {
+    let log = Log::default();
+    let engine = Headless::new(7, 2);
+    let cx = engine.init(Form {
+        log: log.clone(),
+        capture: false,
+    });
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec![" first ", " second"]);
+
+    engine.send(&cx, key(KeyCode::Tab));
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec![">first ", " second"]);
+
+    engine.send(&cx, key(KeyCode::Tab));
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec![" first ", ">second"]);
+
+    engine.send(&cx, key(KeyCode::BackTab));
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec![">first ", " second"]);
+    assert!(logged(&log).is_empty());
+}
+
+#[test]
+fn keys_go_to_the_focused_component_and_bubble_up() {
+    let log = Log::default();
+    let engine = Headless::new(7, 2);
+    let cx = engine.init(Form {
+        log: log.clone(),
+        capture: false,
+    });
+
+    engine.send(&cx, key(KeyCode::Char('a')));
+    assert_eq!(logged(&log), vec!["form a", "first a", "second a"]);
+
+    engine.send(&cx, key(KeyCode::Tab));
+    engine.send(&cx, key(KeyCode::Tab));
+    engine.send(&cx, key(KeyCode::Char('b')));
+    assert_eq!(logged(&log), vec!["second b", "form b"]);
+}
+
+#[test]
+fn capture_handlers_stop_keys_before_the_target() {
+    let log = Log::default();
+    let engine = Headless::new(7, 2);
+    let cx = engine.init(Form {
+        log: log.clone(),
+        capture: true,
+    });
+
+    engine.send(&cx, key(KeyCode::Tab));
+    engine.send(&cx, key(KeyCode::Char('c')));
+    assert!(logged(&log).is_empty());
+}
+
+#[test]
+fn stopped_keys_are_reported_as_handled() {
+    let ctrl_c = Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    let engine = Headless::new(7, 2);
+    let cx = engine.init(Form {
+        log: Log::default(),
+        capture: false,
+    });
+    assert!(!engine.send(&cx, ctrl_c.clone()));
+    assert!(engine.send(&cx, key(KeyCode::Tab)));
+    assert!(!engine.send(&cx, ctrl_c.clone()));
+
+    let engine = Headless::new(7, 2);
+    let cx = engine.init(Form {
+        log: Log::default(),
+        capture: true,
+    });
+    assert!(engine.send(&cx, ctrl_c.clone()));
+    engine.send(&cx, key(KeyCode::Tab));
+    assert!(engine.send(&cx, ctrl_c));
+}
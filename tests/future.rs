@@ -0,0 +1,151 @@
+#![cfg(feature = "async")]
+
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use osui::prelude::*;
+
+/// Tracks whether a future was started and dropped
+#[derive(Default)]
+struct Probe {
+    started: AtomicBool,
+    dropped: AtomicBool,
+}
+
+/// Marks its probe dropped when dropped along with the future owning it
+struct DropFlag(Arc<Probe>);
+
+impl Probe {
+    /// Marks the probe started, returning the flag for the future to own
+    fn start(self: &Arc<Self>) -> DropFlag {
+        self.started.store(true, Ordering::SeqCst);
+        DropFlag(self.clone())
+    }
+
+    fn started(&self) -> bool {
+        self.started.load(Ordering::SeqCst)
+    }
+
+    fn dropped(&self) -> bool {
+        self.dropped.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Steps the engine until `done` holds, for up to a second
+fn eventually(engine: &Headless, cx: &Arc<Context>, done: impl Fn() -> bool) -> bool {
+    let start = Instant::now();
+    loop {
+        engine.step(cx);
+        if done() {
+            return true;
+        }
+        if start.elapsed() > Duration::from_secs(1) {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[component]
+fn Pending(cx: &Arc<Context>, probe: Arc<Probe>) -> View {
+    let probe = probe.clone();
+    use_future(
+        cx,
+        move || {
+            let flag = probe.start();
+            async move {
+                let _flag = flag;
+                std::future::pending::<()>().await
+            }
+        },
+        &[],
+    );
+    Arc::new(|ctx| ctx.draw_text(Point { x: 0, y: 0 }, "waiting"))
+}
+
+#[component]
+fn Toggle(cx: &Arc<Context>, show: State<bool>, probe: Arc<Probe>) -> View {
+    let (show, probe) = (show.clone(), probe.clone());
+    rsx! {
+        %show, ref probe if show.get_dl() {
+            Pending { probe: probe.clone() }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn futures_are_cancelled_on_unmount() {
+    let show = State::new(true);
+    let probe = Arc::new(Probe::default());
+    let engine = Headless::new(7, 1);
+    let cx = engine.init(Toggle {
+        show: show.clone(),
+        probe: probe.clone(),
+    });
+    assert!(eventually(&engine, &cx, || probe.started()));
+    assert_eq!(engine.lines(), vec!["waiting"]);
+    assert!(!probe.dropped());
+
+    show.set(false);
+    assert!(eventually(&engine, &cx, || probe.dropped()));
+    assert_eq!(engine.lines(), vec!["       "]);
+}
+
+#[component]
+fn Fetch(cx: &Arc<Context>, id: State<u32>, probe: Arc<Probe>) -> View {
+    let resource = use_resource(
+        cx,
+        {
+            let (id, probe) = (id.clone(), probe.clone());
+            move || {
+                let id = id.get_dl();
+                let flag = (id == 0).then(|| probe.start());
+                async move {
+                    let _flag = flag;
+                    if id == 0 {
+                        std::future::pending::<()>().await;
+                    }
+                    Ok::<_, String>(id * 10)
+                }
+            }
+        },
+        &[id],
+    );
+
+    Arc::new(move |ctx| {
+        let text = match resource.get_dl() {
+            Resource::Loading => String::from("loading"),
+            Resource::Ready(value) => format!("ready {value}"),
+            Resource::Error(error) => error,
+        };
+        ctx.draw_text(Point { x: 0, y: 0 }, &text)
+    })
+}
+
+#[test]
+fn resources_cancel_the_previous_run_when_restarted() {
+    let id = State::new(0);
+    let probe = Arc::new(Probe::default());
+    let engine = Headless::new(8, 1);
+    let cx = engine.init(Fetch {
+        id: id.clone(),
+        probe: probe.clone(),
+    });
+    assert!(eventually(&engine, &cx, || probe.started()));
+    assert_eq!(engine.lines(), vec!["loading "]);
+
+    id.set(4);
+    assert!(eventually(&engine, &cx, || {
+        engine.lines() == vec!["ready 40"]
+    }));
+    assert!(probe.dropped());
+}
@@ -0,0 +1,147 @@
+use osui::prelude::*;
+
+fn size(width: u16, height: u16) -> Size {
+    Size { width, height }
+}
+
+fn area(x: u16, y: u16, width: u16, height: u16) -> Area {
+    Area {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+fn rects(areas: Vec<Area>) -> Vec<(u16, u16, u16, u16)> {
+    areas
+        .into_iter()
+        .map(|a| (a.x, a.y, a.width, a.height))
+        .collect()
+}
+
+fn named(name: &str) -> Option<GridPlacement> {
+    Some(GridPlacement {
+        area: Some(name.to_string()),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn tracks_are_sized_fixed_then_auto_then_fr() {
+    let layout = GridLayout {
+        columns: vec![Track::Length(3), Track::Auto, Track::Fr(1), Track::Fr(2)],
+        ..Default::default()
+    };
+    let items = [
+        (size(1, 1), None),
+        (size(5, 2), None),
+        (size(0, 1), None),
+        (size(0, 1), None),
+    ];
+
+    assert_eq!(
+        rects(layout.arrange(&area(0, 0, 20, 5), &items)),
+        vec![(0, 0, 3, 2), (3, 0, 5, 2), (8, 0, 4, 2), (12, 0, 8, 2)]
+    );
+}
+
+#[test]
+fn spans_cover_the_tracks_and_gaps_between_them() {
+    let layout = GridLayout {
+        rows: vec![Track::Length(1); 3],
+        columns: vec![Track::Length(2), Track::Length(3), Track::Fr(1)],
+        column_gap: 1,
+        ..Default::default()
+    };
+    let items = [
+        (
+            size(0, 0),
+            Some(GridPlacement {
+                row: Some(0),
+                column: Some(0),
+                column_span: 2,
+                ..Default::default()
+            }),
+        ),
+        (size(0, 0), None),
+        (
+            size(0, 0),
+            Some(GridPlacement {
+                row_span: 2,
+                ..Default::default()
+            }),
+        ),
+    ];
+
+    assert_eq!(
+        rects(layout.arrange(&area(0, 0, 10, 3), &items)),
+        vec![(0, 0, 6, 1), (7, 0, 3, 1), (0, 1, 2, 2)]
+    );
+}
+
+#[test]
+fn named_areas_place_children_and_unknown_names_are_auto_placed() {
+    let layout = GridLayout {
+        rows: vec![Track::Length(1), Track::Fr(1)],
+        columns: vec![Track::Length(4), Track::Fr(1)],
+        areas: vec!["head head".to_string(), "side main".to_string()],
+        ..Default::default()
+    };
+    let items = [
+        (size(0, 0), named("main")),
+        (size(0, 0), named("head")),
+        (size(0, 0), named("side")),
+        (size(0, 1), named("nope")),
+    ];
+
+    assert_eq!(
+        rects(layout.arrange(&area(0, 0, 10, 5), &items)),
+        vec![(4, 1, 6, 3), (0, 0, 10, 1), (0, 1, 4, 3), (0, 4, 4, 1)]
+    );
+}
+
+#[test]
+fn tracks_overflowing_the_area_are_clipped() {
+    let layout = GridLayout {
+        rows: vec![Track::Length(3), Track::Length(3)],
+        columns: vec![Track::Length(6), Track::Auto],
+        padding: Padding::all(1),
+        ..Default::default()
+    };
+    let items = [(size(0, 0), None), (size(20, 1), None), (size(0, 0), None)];
+
+    assert_eq!(
+        rects(layout.arrange(&area(0, 0, 10, 6), &items)),
+        vec![(1, 1, 6, 3), (7, 1, 2, 3), (1, 4, 6, 1)]
+    );
+}
+
+#[component]
+fn Dashboard(cx: &Arc<Context>) -> View {
+    rsx! {
+        Grid {
+            columns: vec![Track::Length(4), Track::Fr(1)],
+            areas: vec!["head head".to_string(), "side main".to_string()],
+            column_gap: 1,
+            ..Default::default()
+
+            impl size_auto, grid_area("main")
+            "main"
+            impl size_auto, grid_area("head")
+            "title"
+            impl size_auto, grid_area("side")
+            "nav"
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn grid_draws_children_in_their_areas() {
+    let engine = Headless::new(10, 2);
+    let cx = engine.init(Dashboard {});
+    engine.step(&cx);
+
+    assert_eq!(engine.lines(), vec!["title     ", "nav  main "]);
+}
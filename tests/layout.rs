@@ -0,0 +1,165 @@
+use osui::prelude::*;
+
+fn size(width: u16, height: u16) -> Size {
+    Size { width, height }
+}
+
+fn area(x: u16, y: u16, width: u16, height: u16) -> Area {
+    Area {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+fn rects(areas: Vec<Area>) -> Vec<(u16, u16, u16, u16)> {
+    areas
+        .into_iter()
+        .map(|a| (a.x, a.y, a.width, a.height))
+        .collect()
+}
+
+fn row() -> FlexLayout {
+    FlexLayout::default()
+}
+
+#[test]
+fn free_space_grows_children_by_their_share() {
+    let items = [
+        (size(2, 1), FlexStyle::new().grow(1)),
+        (size(2, 1), FlexStyle::new().grow(2)),
+        (size(2, 1), FlexStyle::new()),
+    ];
+
+    assert_eq!(
+        rects(row().arrange(&area(0, 0, 10, 2), &items)),
+        vec![(0, 0, 4, 2), (4, 0, 4, 2), (8, 0, 2, 2)]
+    );
+}
+
+#[test]
+fn overflow_shrinks_children_by_size_and_factor() {
+    let items = [
+        (size(6, 1), FlexStyle::new()),
+        (size(3, 1), FlexStyle::new()),
+    ];
+    assert_eq!(
+        rects(row().arrange(&area(0, 0, 6, 1), &items)),
+        vec![(0, 0, 4, 1), (4, 0, 2, 1)]
+    );
+
+    let items = [
+        (size(4, 1), FlexStyle::new().shrink(0)),
+        (size(4, 1), FlexStyle::new()),
+    ];
+    assert_eq!(
+        rects(row().arrange(&area(0, 0, 6, 1), &items)),
+        vec![(0, 0, 4, 1), (4, 0, 2, 1)]
+    );
+}
+
+#[test]
+fn large_shrink_factors_do_not_overflow() {
+    let style = FlexStyle::new()
+        .basis(Constraint::Length(60000))
+        .shrink(u16::MAX);
+    let items = [(size(0, 1), style), (size(0, 1), style)];
+
+    assert_eq!(
+        rects(row().arrange(&area(0, 0, 60000, 1), &items)),
+        vec![(0, 0, 30000, 1), (30000, 0, 30000, 1)]
+    );
+}
+
+#[test]
+fn justify_places_the_free_space() {
+    let items: Vec<_> = (0..3).map(|_| (size(2, 1), FlexStyle::new())).collect();
+    let xs = |justify| {
+        FlexLayout { justify, ..row() }
+            .arrange(&area(0, 0, 10, 1), &items)
+            .into_iter()
+            .map(|a| a.x)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(xs(Justify::Start), vec![0, 2, 4]);
+    assert_eq!(xs(Justify::End), vec![4, 6, 8]);
+    assert_eq!(xs(Justify::Center), vec![2, 4, 6]);
+    assert_eq!(xs(Justify::SpaceBetween), vec![0, 4, 8]);
+    assert_eq!(xs(Justify::SpaceAround), vec![0, 3, 6]);
+    assert_eq!(xs(Justify::SpaceEvenly), vec![1, 4, 7]);
+}
+
+#[test]
+fn align_places_children_on_the_cross_axis() {
+    let items = [
+        (size(1, 1), FlexStyle::new()),
+        (size(1, 1), FlexStyle::new().align_self(Align::Stretch)),
+    ];
+    let first = |align| {
+        let areas = rects(FlexLayout { align, ..row() }.arrange(&area(0, 0, 2, 5), &items));
+        assert_eq!(areas[1], (1, 0, 1, 5));
+        (areas[0].1, areas[0].3)
+    };
+
+    assert_eq!(first(Align::Start), (0, 1));
+    assert_eq!(first(Align::End), (4, 1));
+    assert_eq!(first(Align::Center), (2, 1));
+    assert_eq!(first(Align::Stretch), (0, 5));
+}
+
+#[test]
+fn gaps_and_padding_are_kept_free() {
+    let layout = FlexLayout {
+        gap: 1,
+        padding: Padding::all(1),
+        ..row()
+    };
+    let items = [
+        (size(2, 1), FlexStyle::new()),
+        (size(2, 1), FlexStyle::new()),
+        (size(2, 1), FlexStyle::new().grow(1)),
+    ];
+
+    assert_eq!(
+        rects(layout.arrange(&area(0, 0, 12, 4), &items)),
+        vec![(1, 1, 2, 2), (4, 1, 2, 2), (7, 1, 4, 2)]
+    );
+
+    let column = FlexLayout {
+        direction: Direction::Column,
+        gap: 1,
+        padding: Padding::symmetric(1, 2),
+        ..row()
+    };
+    let items = [
+        (size(3, 1), FlexStyle::new()),
+        (size(5, 2), FlexStyle::new()),
+    ];
+    let measured = column.measure(&items);
+    assert_eq!((measured.width, measured.height), (9, 6));
+}
+
+#[component]
+fn Nested(cx: &Arc<Context>) -> View {
+    rsx! {
+        FlexColumn {
+            "top"
+            FlexRow {
+                "ab"
+                "cd"
+            }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn nested_flex_containers_lay_out_inside_their_parent() {
+    let engine = Headless::new(6, 3);
+    let cx = engine.init(Nested {});
+    engine.step(&cx);
+
+    assert_eq!(engine.lines(), vec!["top   ", "abcd  ", "      "]);
+}
@@ -0,0 +1,82 @@
+use std::{sync::mpsc, time::Duration};
+
+use osui::prelude::*;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+
+fn press(engine: &Headless, cx: &Arc<Context>, code: KeyCode) {
+    engine.send(cx, Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    engine.step(cx);
+}
+
+fn scroll_down(engine: &Headless, cx: &Arc<Context>) {
+    engine.send(
+        cx,
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        }),
+    );
+    engine.step(cx);
+}
+
+#[test]
+fn only_the_visible_rows_follow_the_selection() {
+    let items = State::new((0..10).map(|i| format!("item {i}")).collect::<Vec<_>>());
+    let selected = State::new(0);
+    let engine = Headless::new(6, 3);
+    let cx = engine.init(List {
+        items,
+        selected: selected.clone(),
+        ..Default::default()
+    });
+    engine.step(&cx);
+    press(&engine, &cx, KeyCode::Tab);
+    assert_eq!(engine.lines(), vec!["item 0", "item 1", "item 2"]);
+    assert!(engine.cell(0, 0).unwrap().style.reverse);
+    assert!(!engine.cell(0, 1).unwrap().style.reverse);
+
+    press(&engine, &cx, KeyCode::End);
+    assert_eq!(selected.get_dl(), 9);
+    assert_eq!(engine.lines(), vec!["item 7", "item 8", "item 9"]);
+    assert!(engine.cell(0, 2).unwrap().style.reverse);
+
+    press(&engine, &cx, KeyCode::PageUp);
+    assert_eq!(selected.get_dl(), 6);
+    assert_eq!(engine.lines(), vec!["item 6", "item 7", "item 8"]);
+
+    press(&engine, &cx, KeyCode::Home);
+    scroll_down(&engine, &cx);
+    assert_eq!(selected.get_dl(), 0);
+    assert_eq!(engine.lines(), vec!["item 3", "item 4", "item 5"]);
+}
+
+#[test]
+fn items_may_read_the_list_while_drawn() {
+    let items = State::new(vec![1, 2]);
+    let engine = Headless::new(3, 2);
+    let cx = engine.init(List {
+        items: items.clone(),
+        selected: State::new(0),
+        render_item: Arc::new({
+            let items = items.clone();
+            move |ctx, item: &i32, _| {
+                let total = items.get().len();
+                ctx.draw_text(Point { x: 0, y: 0 }, &format!("{item}/{total}"))
+            }
+        }),
+    });
+
+    let (done, rendered) = mpsc::channel();
+    std::thread::spawn(move || {
+        engine.step(&cx);
+        let _ = done.send(engine.lines());
+    });
+
+    let lines = rendered
+        .recv_timeout(Duration::from_secs(1))
+        .expect("drawing an item deadlocked");
+    assert_eq!(lines, vec!["1/2", "2/2"]);
+}
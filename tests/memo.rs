@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use osui::prelude::*;
+
+#[component]
+fn Doubled(_cx: &Arc<Context>, count: State<u32>, computations: Arc<AtomicUsize>) -> View {
+    let doubled = use_memo(
+        {
+            let (count, computations) = (count.clone(), computations.clone());
+            move || {
+                computations.fetch_add(1, Ordering::SeqCst);
+                *count.get() * 2
+            }
+        },
+        &[count],
+    );
+
+    Arc::new(move |ctx| ctx.draw_text(Point { x: 0, y: 0 }, &doubled.get_dl().to_string()))
+}
+
+#[component]
+fn Parent(
+    cx: &Arc<Context>,
+    tick: State<u32>,
+    count: State<u32>,
+    computations: Arc<AtomicUsize>,
+) -> View {
+    let (count, computations) = (count.clone(), computations.clone());
+    rsx! {
+        %tick, ref count, ref computations for _ in 0..1 {
+            Doubled { count: count.clone(), computations: computations.clone() }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn memos_recompute_only_when_a_dependency_changes() {
+    let tick = State::new(0);
+    let count = State::new(1);
+    let computations = Arc::new(AtomicUsize::new(0));
+    let engine = Headless::new(4, 1);
+    let cx = engine.init(Parent {
+        tick: tick.clone(),
+        count: count.clone(),
+        computations: computations.clone(),
+    });
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["2   "]);
+    assert_eq!(computations.load(Ordering::SeqCst), 1);
+
+    tick.set(1);
+    engine.step(&cx);
+    tick.set(2);
+    engine.step(&cx);
+    assert_eq!(computations.load(Ordering::SeqCst), 1);
+
+    count.set(3);
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["6   "]);
+    assert_eq!(computations.load(Ordering::SeqCst), 2);
+}
+
+#[component]
+fn Progress(_cx: &Arc<Context>, count: State<u32>, total: State<u32>) -> View {
+    let (count, total) = (count.clone(), total.clone());
+    let progress = computed!(count, total => format!("{count}/{total}"));
+
+    Arc::new(move |ctx| ctx.draw_text(Point { x: 0, y: 0 }, &progress.get_dl()))
+}
+
+#[test]
+fn computed_values_follow_their_states() {
+    let count = State::new(3);
+    let engine = Headless::new(5, 1);
+    let cx = engine.init(Progress {
+        count: count.clone(),
+        total: State::new(10),
+    });
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["3/10 "]);
+
+    count.set(4);
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["4/10 "]);
+}
@@ -0,0 +1,80 @@
+use osui::prelude::*;
+
+fn paragraph(width: u16, height: u16, paragraph: Paragraph) -> Vec<String> {
+    let engine = Headless::new(width, height);
+    let cx = engine.init(paragraph);
+    engine.step(&cx);
+    engine.lines()
+}
+
+fn aligned(alignment: Alignment) -> Vec<String> {
+    paragraph(
+        9,
+        2,
+        Paragraph {
+            text: "one two three".to_string(),
+            alignment,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn words_wrap_and_align_within_the_width() {
+    assert_eq!(aligned(Alignment::Left), vec!["one two  ", "three    "]);
+    assert_eq!(aligned(Alignment::Right), vec!["  one two", "    three"]);
+    assert_eq!(aligned(Alignment::Center), vec![" one two ", "  three  "]);
+    assert_eq!(aligned(Alignment::Justify), vec!["one   two", "three    "]);
+}
+
+#[test]
+fn char_wrap_breaks_inside_words() {
+    let lines = paragraph(
+        4,
+        3,
+        Paragraph {
+            text: "abcdefghij".to_string(),
+            wrap: Wrap::Char,
+            ..Default::default()
+        },
+    );
+    assert_eq!(lines, vec!["abcd", "efgh", "ij  "]);
+}
+
+#[test]
+fn scrolled_text_is_cut_off_with_an_ellipsis() {
+    let lines = paragraph(
+        9,
+        1,
+        Paragraph {
+            text: "one two three four five".to_string(),
+            scroll: 1,
+            ellipsis: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(lines, vec!["three…   "]);
+}
+
+#[component]
+fn Help(cx: &Arc<Context>) -> View {
+    rsx! {
+        FlexColumn {
+            impl height_auto
+            Paragraph {
+                text: "one two three".to_string(),
+                ..Default::default()
+            }
+            "next"
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn auto_height_counts_the_wrapped_lines() {
+    let engine = Headless::new(9, 3);
+    let cx = engine.init(Help {});
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["one two  ", "three    ", "next     "]);
+}
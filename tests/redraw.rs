@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use osui::prelude::*;
+
+/// State changes wake every engine, so tests checking that no redraw was
+/// requested must not run alongside each other
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Checks for a pending redraw without blocking, clearing it
+fn requested(cx: &Arc<Context>) -> bool {
+    cx.get_scheduler()
+        .unwrap()
+        .redraw_signal()
+        .wait(Some(Duration::ZERO))
+}
+
+fn engine() -> (Headless, Arc<Context>) {
+    let engine = Headless::new(1, 1);
+    let cx = engine.init(Arc::new(|_: &mut DrawContext| {}) as View);
+    requested(&cx);
+    (engine, cx)
+}
+
+#[test]
+fn context_redraws_only_wake_their_own_engine() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (_first, first) = engine();
+    let (_second, second) = engine();
+
+    first.request_redraw();
+    assert!(requested(&first));
+    assert!(!requested(&second));
+
+    second.refresh();
+    assert!(!requested(&first));
+    assert!(requested(&second));
+}
+
+#[test]
+fn state_changes_wake_every_engine() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (_first, first) = engine();
+    let (_second, second) = engine();
+
+    State::new(0).set(1);
+    assert!(requested(&first));
+    assert!(requested(&second));
+}
+
+#[test]
+fn redraws_inside_a_batch_wait_for_its_end() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (_engine, cx) = engine();
+
+    osui::batch(|| {
+        cx.request_redraw();
+        assert!(!requested(&cx));
+    });
+    assert!(requested(&cx));
+}
@@ -0,0 +1,54 @@
+use osui::prelude::*;
+
+#[component]
+fn Label(_cx: &Arc<Context>, text: State<&'static str>) -> View {
+    let text = text.clone();
+    Arc::new(move |ctx| ctx.draw_text(Point { x: 1, y: 1 }, text.get_dl()))
+}
+
+#[test]
+fn diff_returns_only_changed_cells() {
+    let text = State::new("cat");
+    let engine = Headless::new(6, 2);
+    let cx = engine.init(Label { text: text.clone() });
+    engine.step(&cx);
+    let previous = engine.buffer();
+
+    text.set("car");
+    engine.step(&cx);
+    let next = engine.buffer();
+
+    let changed: Vec<_> = previous
+        .diff(&next)
+        .into_iter()
+        .map(|(x, y, cell)| (x, y, cell.symbol.clone()))
+        .collect();
+    assert_eq!(changed, vec![(3, 1, String::from("r"))]);
+    assert!(next.diff(&next).is_empty());
+}
+
+#[test]
+fn diff_returns_every_cell_after_a_resize() {
+    let text = State::new("cat");
+    let engine = Headless::new(6, 2);
+    let cx = engine.init(Label { text });
+    engine.step(&cx);
+    let previous = engine.buffer();
+
+    engine.resize(&cx, 4, 2);
+    engine.step(&cx);
+
+    assert_eq!(previous.diff(&engine.buffer()).len(), 8);
+    assert_eq!(engine.lines(), vec!["    ", " cat"]);
+}
+
+#[test]
+fn wide_characters_cover_the_next_cell() {
+    let text = State::new("日本");
+    let engine = Headless::new(6, 2);
+    let cx = engine.init(Label { text });
+    engine.step(&cx);
+
+    let symbols: Vec<_> = (1..5).map(|x| engine.cell(x, 1).unwrap().symbol).collect();
+    assert_eq!(symbols, vec!["日", "", "本", ""]);
+}
@@ -0,0 +1,104 @@
+use osui::prelude::*;
+
+/// Row showing a label and how often it was clicked
+#[component]
+fn Row(cx: &Arc<Context>, label: Arc<str>) -> View {
+    let clicks = use_state(0u32);
+    cx.on_event({
+        let clicks = clicks.clone();
+        move |_, _: &Click| *clicks.get() += 1
+    });
+
+    let label = label.clone();
+    Arc::new(move |ctx| {
+        ctx.draw_text(
+            Point { x: 0, y: 0 },
+            &format!("{label} {}", clicks.get_dl()),
+        )
+    })
+}
+
+/// Event clicking every row
+struct Click;
+
+#[component]
+fn Todos(cx: &Arc<Context>, todos: State<Vec<(u32, String)>>) -> View {
+    let todos = todos.clone();
+    rsx! {
+        FlexColumn {
+            %todos for (id, name) in todos.get_dl() key id {
+                Row { label: format!("{id}:{name}").into() }
+            }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn keyed_items_update_in_place() {
+    let todos = State::new(vec![(1, String::from("milk")), (2, String::from("eggs"))]);
+    let engine = Headless::new(10, 2);
+    let cx = engine.init(Todos {
+        todos: todos.clone(),
+    });
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["1:milk 0  ", "2:eggs 0  "]);
+
+    cx.emit_event(Click);
+    todos.get()[1].1 = String::from("ham");
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["1:milk 1  ", "2:ham 1   "]);
+}
+
+#[test]
+fn keyed_items_follow_their_key() {
+    let todos = State::new(vec![(1, String::from("milk")), (2, String::from("eggs"))]);
+    let engine = Headless::new(10, 2);
+    let cx = engine.init(Todos {
+        todos: todos.clone(),
+    });
+    engine.step(&cx);
+
+    cx.emit_event(Click);
+    todos.set(vec![(3, String::from("tea")), (1, String::from("milk"))]);
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["3:tea 0   ", "1:milk 1  "]);
+}
+
+#[derive(Clone, Copy)]
+enum Status {
+    Loading,
+    Failed(u16),
+}
+
+#[component]
+fn Request(cx: &Arc<Context>, status: State<Status>) -> View {
+    let status = status.clone();
+    rsx! {
+        %status match status.get_dl() {
+            Status::Loading => { Row { label: "loading".into() } }
+            Status::Failed(code) => { Row { label: format!("error {code}").into() } }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn match_arm_keeps_state_with_new_bindings() {
+    let status = State::new(Status::Failed(404));
+    let engine = Headless::new(12, 1);
+    let cx = engine.init(Request {
+        status: status.clone(),
+    });
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["error 404 0 "]);
+
+    cx.emit_event(Click);
+    status.set(Status::Failed(500));
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["error 500 1 "]);
+
+    status.set(Status::Loading);
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["loading 0   "]);
+}
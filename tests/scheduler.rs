@@ -0,0 +1,146 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+use osui::prelude::*;
+
+/// Effect counting its runs
+fn counter() -> (Arc<AtomicUsize>, EffectJob) {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let job: EffectJob = Arc::new({
+        let runs = runs.clone();
+        move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+    (runs, job)
+}
+
+/// Waits up to a second for `done` to hold
+fn eventually(done: impl Fn() -> bool) -> bool {
+    let start = Instant::now();
+    while !done() {
+        if start.elapsed() > Duration::from_secs(1) {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    true
+}
+
+#[test]
+fn effects_run_once_however_often_they_are_scheduled() {
+    let scheduler = Arc::new(EffectScheduler::new());
+    let (first, first_job) = counter();
+    let (second, second_job) = counter();
+
+    scheduler.schedule(first_job.clone());
+    scheduler.schedule(second_job);
+    scheduler.schedule(first_job);
+    scheduler.run_pending();
+
+    assert_eq!(first.load(Ordering::SeqCst), 1);
+    assert_eq!(second.load(Ordering::SeqCst), 1);
+
+    scheduler.run_pending();
+    assert_eq!(first.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn effects_scheduling_themselves_are_bounded_per_frame() {
+    let scheduler = Arc::new(EffectScheduler::new());
+    let runs = Arc::new(AtomicUsize::new(0));
+    let this = Arc::new(OnceLock::<EffectJob>::new());
+    let job: EffectJob = Arc::new({
+        let scheduler = Arc::downgrade(&scheduler);
+        let runs = runs.clone();
+        let this = this.clone();
+        move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+            if let (Some(scheduler), Some(job)) = (scheduler.upgrade(), this.get()) {
+                scheduler.schedule(job.clone());
+            }
+        }
+    });
+    let _ = this.set(job.clone());
+
+    scheduler.schedule(job);
+    scheduler.run_pending();
+    let first = runs.load(Ordering::SeqCst);
+    assert_eq!(first, 16);
+
+    scheduler.run_pending();
+    assert_eq!(runs.load(Ordering::SeqCst), first * 2);
+}
+
+#[test]
+fn effects_never_run_on_two_workers_at_once() {
+    let scheduler = Arc::new(EffectScheduler::new());
+    let running = Arc::new(AtomicUsize::new(0));
+    let overlaps = Arc::new(AtomicUsize::new(0));
+    let (runs, count) = counter();
+    let job: EffectJob = Arc::new({
+        let running = running.clone();
+        let overlaps = overlaps.clone();
+        move || {
+            if running.fetch_add(1, Ordering::SeqCst) > 0 {
+                overlaps.fetch_add(1, Ordering::SeqCst);
+            }
+            std::thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            count();
+        }
+    });
+
+    scheduler.set_workers(4);
+    for _ in 0..20 {
+        scheduler.schedule(job.clone());
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    assert!(eventually(|| {
+        runs.load(Ordering::SeqCst) > 0 && running.load(Ordering::SeqCst) == 0
+    }));
+    scheduler.set_workers(0);
+    assert_eq!(overlaps.load(Ordering::SeqCst), 0);
+}
+
+#[component]
+fn Watcher(_cx: &Arc<Context>, value: State<i32>, runs: Arc<AtomicUsize>) -> View {
+    let runs = runs.clone();
+    use_effect(
+        move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+        },
+        &[value],
+    );
+    Arc::new(|_| {})
+}
+
+#[test]
+fn trees_without_an_engine_run_effects_in_the_background() {
+    let value = State::new(0);
+    let runs = Arc::new(AtomicUsize::new(0));
+    let cx = Context::new(
+        Watcher {
+            value: value.clone(),
+            runs: runs.clone(),
+        },
+        Headless::new(1, 1).executor(),
+    );
+    cx.refresh();
+    assert!(cx.get_scheduler().is_none());
+
+    osui::batch(|| {
+        value.set(1);
+        value.set(2);
+    });
+    assert!(eventually(|| runs.load(Ordering::SeqCst) == 1));
+
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
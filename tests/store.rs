@@ -0,0 +1,100 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::OnceLock;
+
+use osui::prelude::*;
+
+type Counter = Store<u32, u32>;
+
+#[test]
+fn actions_dispatched_by_the_reducer_run_after_it_in_order() {
+    let store: Arc<OnceLock<Store<Vec<u32>, u32>>> = Arc::new(OnceLock::new());
+    let handle = store.clone();
+    let _ = store.set(Store::new(Vec::new(), move |log: &mut Vec<u32>, action| {
+        if action == 1 {
+            let store = handle.get().unwrap();
+            store.dispatch(2);
+            store.dispatch(3);
+            // Queued actions have not run yet
+            assert!(store.get_dl().is_empty());
+        }
+        log.push(action);
+    }));
+    let store = store.get().unwrap();
+
+    store.dispatch(1);
+    store.dispatch(4);
+
+    assert_eq!(store.get_dl(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn a_panicking_reducer_does_not_block_later_dispatches() {
+    let store: Counter = Store::new(0, |count: &mut u32, action| {
+        assert_ne!(action, 0, "cannot add nothing");
+        *count += action;
+    });
+
+    let result = catch_unwind(AssertUnwindSafe(|| store.dispatch(0)));
+    assert!(result.is_err());
+
+    store.dispatch(2);
+    assert_eq!(store.get_dl(), 2);
+}
+
+#[component]
+fn Count(cx: &Arc<Context>) -> View {
+    let store = use_context::<Counter>(cx);
+
+    Arc::new(move |ctx| {
+        let text = store
+            .as_ref()
+            .map_or("none".to_string(), |store| store.get_dl().to_string());
+        ctx.draw_text(Point { x: 0, y: 0 }, &text);
+    })
+}
+
+#[component]
+fn Shadowed(cx: &Arc<Context>) -> View {
+    cx.provide(use_store(100, |count: &mut u32, action: u32| {
+        *count += action
+    }));
+
+    rsx! { Count {} }.view(cx)
+}
+
+#[component]
+fn App(cx: &Arc<Context>, store: Counter) -> View {
+    cx.provide(store.clone());
+
+    rsx! {
+        FlexColumn {
+            Count {}
+            Shadowed {}
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn use_context_finds_the_nearest_provided_store() {
+    let store: Counter = Store::new(1, |count: &mut u32, action| *count += action);
+    let engine = Headless::new(4, 2);
+    let cx = engine.init(App {
+        store: store.clone(),
+    });
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["1   ", "100 "]);
+
+    store.dispatch(2);
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["3   ", "100 "]);
+}
+
+#[test]
+fn use_context_without_a_provider_finds_nothing() {
+    let engine = Headless::new(4, 1);
+    let cx = engine.init(Count {});
+    engine.step(&cx);
+
+    assert_eq!(engine.lines(), vec!["none"]);
+}
@@ -0,0 +1,125 @@
+use osui::prelude::*;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+type Person = (&'static str, u32);
+
+fn press(engine: &Headless, cx: &Arc<Context>, code: KeyCode) {
+    engine.send(cx, Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+}
+
+fn columns() -> Vec<Column<Person>> {
+    vec![
+        Column::new("Name", |p: &Person| p.0.to_string()).sort_by(|a, b| a.0.cmp(b.0)),
+        Column::new("Age", |p: &Person| p.1.to_string())
+            .width(ColumnWidth::Fixed(3))
+            .align(Alignment::Right)
+            .sort_by(|a, b| a.1.cmp(&b.1)),
+    ]
+}
+
+/// Creates a focused table of people
+fn people(width: u16, height: u16) -> (Headless, Arc<Context>, State<Vec<Person>>, State<usize>) {
+    let rows = State::new(vec![("carol", 35), ("alice", 30), ("bob", 25)]);
+    let selected = State::new(0);
+    let engine = Headless::new(width, height);
+    let cx = engine.init(Table {
+        columns: columns(),
+        rows: rows.clone(),
+        selected: selected.clone(),
+        ..Default::default()
+    });
+    engine.step(&cx);
+    press(&engine, &cx, KeyCode::Tab);
+    (engine, cx, rows, selected)
+}
+
+#[test]
+fn number_keys_sort_and_reverse_the_rows() {
+    let (engine, cx, _, _) = people(12, 4);
+    assert_eq!(
+        engine.lines(),
+        vec![
+            "Name   Age  ",
+            "carol   35  ",
+            "alice   30  ",
+            "bob     25  "
+        ]
+    );
+
+    press(&engine, &cx, KeyCode::Char('1'));
+    engine.step(&cx);
+    assert_eq!(
+        engine.lines(),
+        vec![
+            "Name ▲ Age  ",
+            "alice   30  ",
+            "bob     25  ",
+            "carol   35  "
+        ]
+    );
+
+    press(&engine, &cx, KeyCode::Char('1'));
+    engine.step(&cx);
+    assert_eq!(
+        engine.lines(),
+        vec![
+            "Name ▼ Age  ",
+            "carol   35  ",
+            "bob     25  ",
+            "alice   30  "
+        ]
+    );
+
+    press(&engine, &cx, KeyCode::Char('2'));
+    engine.step(&cx);
+    assert_eq!(
+        engine.lines(),
+        vec![
+            "Name   Ag…  ",
+            "bob     25  ",
+            "alice   30  ",
+            "carol   35  "
+        ]
+    );
+}
+
+#[test]
+fn the_selection_stays_on_its_row_across_a_sort() {
+    let (engine, cx, _, selected) = people(12, 4);
+    press(&engine, &cx, KeyCode::Down);
+    assert_eq!(selected.get_dl(), 1);
+
+    press(&engine, &cx, KeyCode::Char('2'));
+    engine.step(&cx);
+    assert_eq!(selected.get_dl(), 1);
+    assert!(engine.cell(0, 2).unwrap().style.reverse);
+    assert_eq!(engine.lines()[2], "alice   30  ");
+
+    press(&engine, &cx, KeyCode::Char('2'));
+    engine.step(&cx);
+    assert_eq!(selected.get_dl(), 1);
+
+    press(&engine, &cx, KeyCode::Char('1'));
+    engine.step(&cx);
+    assert_eq!(selected.get_dl(), 0);
+    assert!(engine.cell(0, 1).unwrap().style.reverse);
+    assert_eq!(engine.lines()[1], "alice   30  ");
+}
+
+#[test]
+fn columns_fit_the_width_and_follow_the_rows() {
+    let (engine, cx, rows, _) = people(8, 4);
+    assert_eq!(
+        engine.lines(),
+        vec!["Name Age", "car…  35", "ali…  30", "bob   25"]
+    );
+
+    engine.resize(&cx, 12, 4);
+    engine.step(&cx);
+    assert_eq!(engine.lines()[1], "carol   35  ");
+
+    rows.set(vec![("bartholomew", 40)]);
+    engine.step(&cx);
+    assert_eq!(engine.lines()[1], "barthol…  40");
+}
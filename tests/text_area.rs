@@ -0,0 +1,94 @@
+use osui::prelude::*;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+fn press(engine: &Headless, cx: &Arc<Context>, code: KeyCode, modifiers: KeyModifiers) {
+    engine.send(cx, Event::Key(KeyEvent::new(code, modifiers)));
+}
+
+fn type_text(engine: &Headless, cx: &Arc<Context>, text: &str) {
+    for c in text.chars() {
+        press(engine, cx, KeyCode::Char(c), KeyModifiers::NONE);
+    }
+}
+
+/// Creates a focused text area bound to `value`
+fn focused_area(value: &State<Vec<String>>, width: u16, height: u16) -> (Headless, Arc<Context>) {
+    let engine = Headless::new(width, height);
+    let cx = engine.init(TextArea {
+        value: value.clone(),
+        ..Default::default()
+    });
+    engine.step(&cx);
+    press(&engine, &cx, KeyCode::Tab, KeyModifiers::NONE);
+    (engine, cx)
+}
+
+fn lines(text: &[&str]) -> Vec<String> {
+    text.iter().map(|l| l.to_string()).collect()
+}
+
+#[test]
+fn enter_splits_lines_and_backspace_joins_them() {
+    let value = State::new(vec![String::new()]);
+    let (engine, cx) = focused_area(&value, 8, 3);
+
+    type_text(&engine, &cx, "ab");
+    press(&engine, &cx, KeyCode::Left, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::Enter, KeyModifiers::NONE);
+    assert_eq!(value.get_dl(), lines(&["a", "b"]));
+
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["a       ", "b       ", "        "]);
+    assert!(engine.cell(0, 1).unwrap().style.reverse);
+
+    press(&engine, &cx, KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(value.get_dl(), lines(&["ab"]));
+}
+
+#[test]
+fn wraps_long_lines() {
+    let value = State::new(lines(&["abcdefgh", "ij"]));
+    let (engine, cx) = focused_area(&value, 4, 4);
+    engine.step(&cx);
+
+    assert_eq!(engine.lines(), vec!["abcd", "efgh", "    ", "ij  "]);
+}
+
+#[test]
+fn selections_span_lines_and_undo_restores_them() {
+    let value = State::new(lines(&["one", "two", "three"]));
+    let (engine, cx) = focused_area(&value, 8, 3);
+
+    press(&engine, &cx, KeyCode::Right, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::Down, KeyModifiers::SHIFT);
+    press(&engine, &cx, KeyCode::Down, KeyModifiers::SHIFT);
+    press(&engine, &cx, KeyCode::Delete, KeyModifiers::NONE);
+    assert_eq!(value.get_dl(), lines(&["ohree"]));
+
+    press(&engine, &cx, KeyCode::Char('z'), KeyModifiers::CONTROL);
+    assert_eq!(value.get_dl(), lines(&["one", "two", "three"]));
+
+    press(&engine, &cx, KeyCode::Char('y'), KeyModifiers::CONTROL);
+    assert_eq!(value.get_dl(), lines(&["ohree"]));
+}
+
+#[test]
+fn keys_after_the_value_shrinks_stay_inside_the_text() {
+    let value = State::new(lines(&["one", "two", "three", "four"]));
+    let (engine, cx) = focused_area(&value, 8, 4);
+
+    press(&engine, &cx, KeyCode::Down, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::Down, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::End, KeyModifiers::SHIFT);
+
+    value.set(vec![String::new()]);
+    type_text(&engine, &cx, "x");
+    assert_eq!(value.get_dl(), lines(&["x"]));
+
+    press(&engine, &cx, KeyCode::Down, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::End, KeyModifiers::SHIFT);
+    value.set(lines(&["a"]));
+    press(&engine, &cx, KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(value.get_dl(), lines(&[""]));
+}
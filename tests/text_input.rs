@@ -0,0 +1,132 @@
+use osui::prelude::*;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+fn press(engine: &Headless, cx: &Arc<Context>, code: KeyCode, modifiers: KeyModifiers) {
+    engine.send(cx, Event::Key(KeyEvent::new(code, modifiers)));
+}
+
+fn type_text(engine: &Headless, cx: &Arc<Context>, text: &str) {
+    for c in text.chars() {
+        press(engine, cx, KeyCode::Char(c), KeyModifiers::NONE);
+    }
+}
+
+/// Creates a focused input bound to `value`
+fn focused_input(value: &State<String>, width: u16) -> (Headless, Arc<Context>) {
+    let engine = Headless::new(width, 1);
+    let cx = engine.init(TextInput {
+        value: value.clone(),
+        ..Default::default()
+    });
+    press(&engine, &cx, KeyCode::Tab, KeyModifiers::NONE);
+    (engine, cx)
+}
+
+#[test]
+fn typing_inserts_at_the_cursor() {
+    let value = State::new(String::new());
+    let (engine, cx) = focused_input(&value, 8);
+
+    type_text(&engine, &cx, "hlo");
+    press(&engine, &cx, KeyCode::Left, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::Left, KeyModifiers::NONE);
+    type_text(&engine, &cx, "el");
+    engine.step(&cx);
+
+    assert_eq!(value.get_dl(), "hello");
+    assert_eq!(engine.lines(), vec!["hello   "]);
+    assert!(engine.cell(3, 0).unwrap().style.reverse);
+}
+
+#[test]
+fn keys_edit_words_and_selections() {
+    let value = State::new(String::from("hello big world"));
+    let (engine, cx) = focused_input(&value, 16);
+
+    press(&engine, &cx, KeyCode::Backspace, KeyModifiers::CONTROL);
+    assert_eq!(value.get_dl(), "hello big ");
+
+    press(&engine, &cx, KeyCode::Home, KeyModifiers::NONE);
+    press(
+        &engine,
+        &cx,
+        KeyCode::Right,
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+    );
+    type_text(&engine, &cx, "goodbye");
+    assert_eq!(value.get_dl(), "goodbye big ");
+
+    press(&engine, &cx, KeyCode::Delete, KeyModifiers::NONE);
+    press(&engine, &cx, KeyCode::Delete, KeyModifiers::CONTROL);
+    assert_eq!(value.get_dl(), "goodbye ");
+}
+
+#[test]
+fn ignores_keys_without_focus() {
+    let value = State::new(String::new());
+    let engine = Headless::new(8, 1);
+    let cx = engine.init(TextInput {
+        value: value.clone(),
+        ..Default::default()
+    });
+
+    type_text(&engine, &cx, "hi");
+    assert_eq!(value.get_dl(), "");
+}
+
+#[test]
+fn scrolls_to_keep_the_cursor_visible() {
+    let value = State::new(String::new());
+    let (engine, cx) = focused_input(&value, 5);
+
+    type_text(&engine, &cx, "abcdefgh");
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["efgh "]);
+
+    press(&engine, &cx, KeyCode::Home, KeyModifiers::NONE);
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["abcde"]);
+}
+
+#[test]
+fn wide_characters_take_two_columns() {
+    let value = State::new(String::new());
+    let (engine, cx) = focused_input(&value, 5);
+
+    type_text(&engine, &cx, "日本語");
+    engine.step(&cx);
+    assert_eq!(engine.lines(), vec!["本語 "]);
+    assert!(engine.cell(4, 0).unwrap().style.reverse);
+}
+
+#[component]
+fn Labeled(cx: &Arc<Context>, label: State<u32>, value: State<String>) -> View {
+    let (label, value) = (label.clone(), value.clone());
+    rsx! {
+        %label, ref value for _ in 0..1 {
+            TextInput { value: value.clone(), placeholder: label.to_string(), ..Default::default() }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn keeps_the_cursor_when_its_parent_re_renders() {
+    let label = State::new(0);
+    let value = State::new(String::new());
+    let engine = Headless::new(8, 1);
+    let cx = engine.init(Labeled {
+        label: label.clone(),
+        value: value.clone(),
+    });
+    press(&engine, &cx, KeyCode::Tab, KeyModifiers::NONE);
+
+    type_text(&engine, &cx, "ab");
+    press(&engine, &cx, KeyCode::Left, KeyModifiers::NONE);
+    label.set(1);
+    engine.step(&cx);
+    type_text(&engine, &cx, "x");
+
+    assert_eq!(value.get_dl(), "axb");
+}
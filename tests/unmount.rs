@@ -0,0 +1,114 @@
+use osui::prelude::*;
+
+/// What the probes did, in order
+type Log = Arc<Mutex<Vec<String>>>;
+
+fn logged(log: &Log) -> Vec<String> {
+    std::mem::take(&mut *log.lock().unwrap())
+}
+
+#[component]
+fn Probe(cx: &Arc<Context>, name: Arc<str>, tick: State<u32>) -> View {
+    let log: Log = cx.consume().unwrap();
+
+    use_unmount({
+        let (name, log) = (name.clone(), log.clone());
+        move || log.lock().unwrap().push(format!("unmount {name}"))
+    });
+    use_effect_cleanup(
+        {
+            let (name, log) = (name.clone(), log.clone());
+            move || {
+                log.lock().unwrap().push(format!("effect {name}"));
+                let (name, log) = (name.clone(), log.clone());
+                move || log.lock().unwrap().push(format!("cleanup {name}"))
+            }
+        },
+        &[tick],
+    );
+
+    Arc::new(|_| {})
+}
+
+#[component]
+fn Toggle(cx: &Arc<Context>, log: Log, show: State<bool>, tick: State<u32>) -> View {
+    cx.provide(log.clone());
+
+    let (show, tick) = (show.clone(), tick.clone());
+    rsx! {
+        %show, ref tick if show.get_dl() {
+            Probe { name: "probe".into(), tick: tick.clone() }
+        }
+    }
+    .view(cx)
+}
+
+#[test]
+fn removed_children_run_their_cleanups() {
+    let log = Log::default();
+    let show = State::new(true);
+    let tick = State::new(0);
+    let engine = Headless::new(1, 1);
+    let cx = engine.init(Toggle {
+        log: log.clone(),
+        show: show.clone(),
+        tick: tick.clone(),
+    });
+
+    tick.set(1);
+    engine.step(&cx);
+    assert_eq!(logged(&log), vec!["effect probe"]);
+
+    show.set(false);
+    engine.step(&cx);
+    assert_eq!(logged(&log), vec!["cleanup probe", "unmount probe"]);
+
+    tick.set(2);
+    engine.step(&cx);
+    assert!(logged(&log).is_empty());
+}
+
+#[test]
+fn effects_clean_up_before_running_again() {
+    let log = Log::default();
+    let tick = State::new(0);
+    let engine = Headless::new(1, 1);
+    let cx = engine.init(Toggle {
+        log: log.clone(),
+        show: State::new(true),
+        tick: tick.clone(),
+    });
+
+    tick.set(1);
+    engine.step(&cx);
+    tick.set(2);
+    engine.step(&cx);
+    assert_eq!(
+        logged(&log),
+        vec!["effect probe", "cleanup probe", "effect probe"]
+    );
+}
+
+#[component]
+fn Nested(cx: &Arc<Context>, log: Log) -> View {
+    cx.provide(log.clone());
+    use_unmount({
+        let log = log.clone();
+        move || log.lock().unwrap().push(String::from("unmount outer"))
+    });
+
+    rsx! {
+        Probe { name: "inner".into(), tick: State::new(0) }
+    }
+    .view(cx)
+}
+
+#[test]
+fn children_unmount_before_their_parent() {
+    let log = Log::default();
+    let engine = Headless::new(1, 1);
+    let cx = engine.init(Nested { log: log.clone() });
+
+    cx.unmount();
+    assert_eq!(logged(&log), vec!["unmount inner", "unmount outer"]);
+}